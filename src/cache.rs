@@ -0,0 +1,376 @@
+//! Response caching.
+//!
+//! A [`ResponseCache`] set on the client with
+//! [`GuardianContentClientBuilder::cache`](crate::GuardianContentClientBuilder::cache)
+//! stores successful responses, keyed on the endpoint and query parameters
+//! of the request, and serves identical requests from the cache until the
+//! entry expires. Cache hits do not count against the rate limit or the
+//! daily quota of the client.
+//!
+//! Each endpoint has its own time to live: sections and editions rarely
+//! change, so they are kept for a day by default, while content searches
+//! are only kept for a minute.
+//!
+//! When an expired entry has an `ETag`, the request is sent with an
+//! `If-None-Match` header, and a `304 Not Modified` response renews
+//! the existing entry instead of downloading it again.
+//!
+//! Responses are stored as they were received, and deserialized on every
+//! hit. Where they are stored is up to the [`CacheStore`], the default
+//! being [`MemoryCache`]. [`DiskCache`] stores them in a directory instead,
+//! so that they survive restarts and can be shared by multiple processes.
+
+use crate::enums::Endpoint;
+use crate::error::Error;
+use crate::transport::HttpResponse;
+use reqwest::header::{HeaderMap, HeaderValue, ETAG, IF_NONE_MATCH};
+use reqwest::StatusCode;
+use serde::{Deserialize, Serialize};
+use serde_json::value::RawValue;
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, SystemTime};
+
+/// A response stored in a cache.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CachedResponse {
+    /// The body of the response, as received from the API.
+    pub body: Vec<u8>,
+    /// The `ETag` header of the response, if it had one.
+    pub etag: Option<String>,
+    /// When the response was received or last revalidated.
+    pub stored_at: SystemTime,
+}
+
+impl CachedResponse {
+    /// Whether the entry is younger than `ttl`.
+    pub fn is_fresh(&self, ttl: Duration) -> bool {
+        self.stored_at.elapsed().is_ok_and(|elapsed| elapsed < ttl)
+    }
+}
+
+/// Where cached responses are stored.
+///
+/// Keys are the normalized URLs of the requests, with the API key redacted.
+/// The async client calls stores on the blocking thread pool, so they
+/// can read and write files, like [`DiskCache`], without stalling it.
+pub trait CacheStore: Debug + Send + Sync {
+    fn get(&self, key: &str) -> Option<CachedResponse>;
+    fn put(&self, key: &str, response: CachedResponse);
+}
+
+impl<T: CacheStore + ?Sized> CacheStore for Arc<T> {
+    fn get(&self, key: &str) -> Option<CachedResponse> {
+        (**self).get(key)
+    }
+
+    fn put(&self, key: &str, response: CachedResponse) {
+        (**self).put(key, response)
+    }
+}
+
+/// A cache store keeping responses in memory.
+///
+/// Expired entries are only replaced when the same request is sent again,
+/// so `max_entries` can be used to bound memory usage: once it is reached,
+/// the oldest entry is evicted to make room for a new one.
+#[derive(Debug, Default)]
+pub struct MemoryCache {
+    entries: Mutex<HashMap<String, CachedResponse>>,
+    max_entries: Option<usize>,
+}
+
+impl MemoryCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keep at most `max_entries` responses in memory.
+    pub fn max_entries(mut self, max_entries: usize) -> Self {
+        self.max_entries = Some(max_entries);
+        self
+    }
+
+    /// The number of responses in the cache.
+    pub fn len(&self) -> usize {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .len()
+    }
+
+    /// Whether the cache contains no responses.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Remove all responses from the cache.
+    pub fn clear(&self) {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clear();
+    }
+}
+
+impl CacheStore for MemoryCache {
+    fn get(&self, key: &str) -> Option<CachedResponse> {
+        self.entries
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(key)
+            .cloned()
+    }
+
+    fn put(&self, key: &str, response: CachedResponse) {
+        let mut entries = self.entries.lock().unwrap_or_else(PoisonError::into_inner);
+
+        if let Some(max_entries) = self.max_entries {
+            while !entries.contains_key(key) && entries.len() >= max_entries.max(1) {
+                let Some(oldest) = entries
+                    .iter()
+                    .min_by_key(|(_, entry)| entry.stored_at)
+                    .map(|(key, _)| key.clone())
+                else {
+                    break;
+                };
+                entries.remove(&oldest);
+            }
+        }
+
+        entries.insert(key.to_owned(), response);
+    }
+}
+
+/// A cache store keeping responses in a directory, one file per request.
+///
+/// Entries survive restarts, and the same directory can be used by several
+/// processes at once: files are written atomically, so readers never see
+/// a partially written entry. Errors reading or writing the directory are
+/// not reported, and only cause the request to be sent to the API.
+///
+/// # Example
+/// ```ignore
+/// let cache = ResponseCache::new(DiskCache::new("/var/cache/my-app/guardian"))
+///         .ttl(Endpoint::Content, Duration::from_secs(7 * 24 * 60 * 60));
+/// ```
+#[derive(Clone, Debug)]
+pub struct DiskCache {
+    dir: PathBuf,
+}
+
+/// The contents of a cache file.
+#[derive(Deserialize, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DiskEntry {
+    key: String,
+    etag: Option<String>,
+    stored_at: SystemTime,
+    body: Box<RawValue>,
+}
+
+impl DiskCache {
+    /// Store responses in `dir`, which is created if needed.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self { dir: dir.into() }
+    }
+
+    /// The path of the file storing the response for `key`.
+    pub fn entry_path(&self, key: &str) -> PathBuf {
+        self.dir
+            .join(format!("{}.json", crate::helpers::fingerprint(key)))
+    }
+
+    /// Remove all responses from the cache.
+    pub fn clear(&self) -> Result<(), Error> {
+        let entries = match fs::read_dir(&self.dir) {
+            Ok(entries) => entries,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(()),
+            Err(err) => return Err(err.into()),
+        };
+
+        for entry in entries {
+            let path = entry?.path();
+            if path
+                .extension()
+                .is_some_and(|extension| extension == "json")
+            {
+                fs::remove_file(path)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn read(&self, key: &str) -> Option<CachedResponse> {
+        let contents = fs::read(self.entry_path(key)).ok()?;
+        let entry: DiskEntry = serde_json::from_slice(&contents).ok()?;
+
+        // Different keys can have the same fingerprint
+        (entry.key == key).then(|| CachedResponse {
+            body: entry.body.get().as_bytes().to_vec(),
+            etag: entry.etag,
+            stored_at: entry.stored_at,
+        })
+    }
+
+    fn write(&self, key: &str, response: CachedResponse) -> Result<(), Error> {
+        let entry = DiskEntry {
+            key: key.to_owned(),
+            etag: response.etag,
+            stored_at: response.stored_at,
+            body: serde_json::from_slice(&response.body)?,
+        };
+
+        // Write to a temporary file first, so that other processes
+        // never read a partially written entry
+        let path = self.entry_path(key);
+        let temporary_path = path.with_extension(format!("{:016x}.tmp", fastrand::u64(..)));
+        fs::create_dir_all(&self.dir)?;
+        fs::write(&temporary_path, serde_json::to_vec(&entry)?)?;
+        fs::rename(&temporary_path, path).inspect_err(|_| {
+            let _ = fs::remove_file(&temporary_path);
+        })?;
+
+        Ok(())
+    }
+}
+
+impl CacheStore for DiskCache {
+    fn get(&self, key: &str) -> Option<CachedResponse> {
+        self.read(key)
+    }
+
+    fn put(&self, key: &str, response: CachedResponse) {
+        let _ = self.write(key, response);
+    }
+}
+
+/// Caching settings of a client: where responses are stored,
+/// and for how long they are served for each endpoint.
+/// Clones share the same store.
+///
+/// # Example
+/// ```ignore
+/// let cache = ResponseCache::in_memory()
+///         .ttl(Endpoint::Content, Duration::from_secs(300));
+///
+/// let client = GuardianContentClient::builder("YOUR_API_KEY")
+///         .cache(cache)
+///         .build()?;
+/// ```
+#[derive(Clone, Debug)]
+pub struct ResponseCache {
+    store: Arc<dyn CacheStore>,
+    ttls: HashMap<Endpoint, Duration>,
+}
+
+impl ResponseCache {
+    /// Cache responses in the given store, with the default time to live
+    /// for each endpoint:
+    /// - [`Endpoint::Content`]: 1 minute
+    /// - [`Endpoint::SingleItem`]: 5 minutes
+    /// - [`Endpoint::Tags`]: 1 hour
+    /// - [`Endpoint::Sections`] and [`Endpoint::Editions`]: 1 day
+    pub fn new(store: impl CacheStore + 'static) -> Self {
+        let ttls = HashMap::from([
+            (Endpoint::Content, Duration::from_secs(60)),
+            (Endpoint::SingleItem, Duration::from_secs(5 * 60)),
+            (Endpoint::Tags, Duration::from_secs(60 * 60)),
+            (Endpoint::Sections, Duration::from_secs(24 * 60 * 60)),
+            (Endpoint::Editions, Duration::from_secs(24 * 60 * 60)),
+        ]);
+
+        Self {
+            store: Arc::new(store),
+            ttls,
+        }
+    }
+
+    /// Cache responses in a [`MemoryCache`] with no size limit.
+    pub fn in_memory() -> Self {
+        Self::new(MemoryCache::new())
+    }
+
+    /// Set how long responses from `endpoint` are served from the cache.
+    /// A time to live of zero means responses are always revalidated.
+    pub fn ttl(mut self, endpoint: Endpoint, ttl: Duration) -> Self {
+        self.ttls.insert(endpoint, ttl);
+        self
+    }
+
+    fn ttl_for(&self, endpoint: &Endpoint) -> Duration {
+        self.ttls.get(endpoint).copied().unwrap_or_default()
+    }
+
+    /// Look up a request in the cache. When the cached entry has expired
+    /// but has an `ETag`, a conditional header is added to the request.
+    pub(crate) fn lookup(&self, key: &str, endpoint: &Endpoint, headers: &mut HeaderMap) -> Lookup {
+        let Some(entry) = self.store.get(key) else {
+            return Lookup::Miss(None);
+        };
+
+        if entry.is_fresh(self.ttl_for(endpoint)) {
+            return Lookup::Hit(entry);
+        }
+
+        if let Some(etag) = entry
+            .etag
+            .as_deref()
+            .and_then(|etag| HeaderValue::from_str(etag).ok())
+        {
+            headers.insert(IF_NONE_MATCH, etag);
+        }
+
+        Lookup::Miss(Some(entry))
+    }
+
+    /// Update the cache with the response to a request that missed it,
+    /// and return the response to use: the stale entry if the API
+    /// confirmed it hasn't changed, or the new response otherwise.
+    pub(crate) fn update(
+        &self,
+        key: &str,
+        stale: Option<CachedResponse>,
+        response: HttpResponse,
+    ) -> HttpResponse {
+        if response.status == StatusCode::NOT_MODIFIED {
+            if let Some(mut entry) = stale {
+                entry.stored_at = SystemTime::now();
+                self.store.put(key, entry.clone());
+                return HttpResponse::json(entry.body);
+            }
+        }
+
+        if response.status.is_success() {
+            let etag = response
+                .headers
+                .get(ETAG)
+                .and_then(|etag| etag.to_str().ok())
+                .map(String::from);
+
+            self.store.put(
+                key,
+                CachedResponse {
+                    body: response.body.clone(),
+                    etag,
+                    stored_at: SystemTime::now(),
+                },
+            );
+        }
+
+        response
+    }
+}
+
+/// The result of looking up a request in the cache.
+pub(crate) enum Lookup {
+    /// A fresh entry that can be used as is.
+    Hit(CachedResponse),
+    /// No usable entry, along with the expired entry if there is one.
+    Miss(Option<CachedResponse>),
+}
@@ -0,0 +1,257 @@
+//! Record and replay API interactions.
+//!
+//! [`CassetteTransport`] wraps another [`Transport`] and saves every response
+//! it returns to a cassette file in a directory, along with the URL of the
+//! request. The same directory can then be used to replay those responses
+//! without network access, which makes tests and demos deterministic.
+//!
+//! Only successful responses are recorded by default, so that a transient
+//! error is not replayed forever. Response headers, such as `ETag` and
+//! `Retry-After`, are recorded along with the body, except for cookies.
+//!
+//! Cassettes are named after the normalized URL of the request, so the order
+//! in which query parameters were set does not matter. API keys are never
+//! written to cassettes: the key sent in the `api-key` header is not recorded,
+//! and an `api-key` query parameter is redacted from the recorded URL.
+
+use crate::error::Error;
+use crate::transport::{HttpResponse, Transport};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue, SET_COOKIE};
+use reqwest::{StatusCode, Url};
+use serde::{Deserialize, Serialize};
+use serde_json::value::RawValue;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+#[cfg(not(feature = "blocking"))]
+use crate::transport::TransportFuture;
+
+/// A transport recording responses to, or replaying them from, a directory.
+///
+/// # Example
+/// ```ignore
+/// // Record responses from the API the first time the tests are run,
+/// // and replay them afterwards
+/// let transport = CassetteTransport::replay_or_record(
+///     "tests/cassettes",
+///     ReqwestTransport::default(),
+/// );
+///
+/// let client = GuardianContentClient::builder("YOUR_API_KEY")
+///         .transport(transport)
+///         .build()?;
+/// ```
+#[derive(Debug)]
+pub struct CassetteTransport {
+    dir: PathBuf,
+    inner: Option<Box<dyn Transport>>,
+    replay: bool,
+    record_errors: bool,
+}
+
+/// The contents of a cassette file. JSON bodies are stored verbatim,
+/// anything else as text.
+#[derive(Deserialize, Serialize)]
+struct Cassette {
+    url: String,
+    status: u16,
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    headers: BTreeMap<String, String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    json: Option<Box<RawValue>>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    text: Option<String>,
+}
+
+impl CassetteTransport {
+    /// Send every request through `inner`, and record the responses in `dir`,
+    /// overwriting existing cassettes.
+    pub fn record(dir: impl Into<PathBuf>, inner: impl Transport + 'static) -> Self {
+        Self {
+            dir: dir.into(),
+            inner: Some(Box::new(inner)),
+            replay: false,
+            record_errors: false,
+        }
+    }
+
+    /// Serve every request from the cassettes in `dir`. Requests without
+    /// a cassette fail with [`Error::MissingCassette`].
+    pub fn replay(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            inner: None,
+            replay: true,
+            record_errors: false,
+        }
+    }
+
+    /// Serve requests from the cassettes in `dir` when they exist, and
+    /// otherwise send them through `inner` and record the responses.
+    pub fn replay_or_record(dir: impl Into<PathBuf>, inner: impl Transport + 'static) -> Self {
+        Self {
+            dir: dir.into(),
+            inner: Some(Box::new(inner)),
+            replay: true,
+            record_errors: false,
+        }
+    }
+
+    /// Also record responses with a status other than `2xx`, e.g. to
+    /// replay how the API responds to an invalid request.
+    /// By default, they are returned but not recorded.
+    pub fn record_errors(mut self, record_errors: bool) -> Self {
+        self.record_errors = record_errors;
+        self
+    }
+
+    /// The path of the cassette for a request.
+    pub fn cassette_path(&self, url: &Url) -> PathBuf {
+        let normalized_url = crate::helpers::normalized_url(url);
+
+        let name = url
+            .path()
+            .trim_matches('/')
+            .chars()
+            .map(|c| if c.is_ascii_alphanumeric() { c } else { '-' })
+            .take(64)
+            .collect::<String>();
+        let name = if name.is_empty() { "root" } else { &name };
+
+        self.dir.join(format!(
+            "{name}-{}.json",
+            crate::helpers::fingerprint(&normalized_url)
+        ))
+    }
+
+    /// Read the recorded response for a request, if replaying is
+    /// enabled and a cassette exists.
+    pub(crate) fn load(&self, url: &Url) -> Result<Option<HttpResponse>, Error> {
+        if !self.replay {
+            return Ok(None);
+        }
+
+        let contents = match fs::read(self.cassette_path(url)) {
+            Ok(contents) => contents,
+            Err(err) if err.kind() == ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err.into()),
+        };
+
+        let cassette: Cassette = serde_json::from_slice(&contents)?;
+        let body = match (cassette.json, cassette.text) {
+            (Some(json), _) => json.get().as_bytes().to_vec(),
+            (None, Some(text)) => text.into_bytes(),
+            (None, None) => Vec::new(),
+        };
+        let status = StatusCode::from_u16(cassette.status)
+            .map_err(|err| std::io::Error::new(ErrorKind::InvalidData, err))?;
+
+        let mut response = HttpResponse::new(status, body);
+        for (name, value) in cassette.headers {
+            let name = HeaderName::try_from(name)
+                .map_err(|err| std::io::Error::new(ErrorKind::InvalidData, err))?;
+            let value = HeaderValue::try_from(value)
+                .map_err(|err| std::io::Error::new(ErrorKind::InvalidData, err))?;
+            response.headers.append(name, value);
+        }
+
+        Ok(Some(response))
+    }
+
+    /// Record the response to a request.
+    pub(crate) fn save(&self, url: &Url, response: &HttpResponse) -> Result<(), Error> {
+        let (json, text) = match serde_json::from_slice::<Box<RawValue>>(&response.body) {
+            Ok(json) => (Some(json), None),
+            Err(_) => (
+                None,
+                Some(String::from_utf8_lossy(&response.body).into_owned()),
+            ),
+        };
+
+        // Headers with several values are joined, as allowed by HTTP
+        let mut headers = BTreeMap::<String, String>::new();
+        for (name, value) in &response.headers {
+            let Ok(value) = value.to_str() else {
+                continue;
+            };
+            if name == SET_COOKIE {
+                continue;
+            }
+            headers
+                .entry(name.as_str().to_owned())
+                .and_modify(|values| {
+                    values.push_str(", ");
+                    values.push_str(value);
+                })
+                .or_insert_with(|| value.to_owned());
+        }
+
+        let cassette = Cassette {
+            url: crate::helpers::normalized_url(url),
+            status: response.status.as_u16(),
+            headers,
+            json,
+            text,
+        };
+
+        let path = self.cassette_path(url);
+        create_parent(&path)?;
+        fs::write(path, serde_json::to_vec_pretty(&cassette)?)?;
+
+        Ok(())
+    }
+
+    /// Record the response to a request sent through the inner transport,
+    /// unless it is an error which should not be recorded.
+    fn save_if_recorded(&self, url: &Url, response: &HttpResponse) -> Result<(), Error> {
+        if response.status.is_success() || self.record_errors {
+            self.save(url, response)?;
+        }
+        Ok(())
+    }
+
+    fn inner(&self, url: &Url) -> Result<&dyn Transport, Error> {
+        self.inner
+            .as_deref()
+            .ok_or_else(|| Error::MissingCassette(crate::helpers::normalized_url(url)))
+    }
+}
+
+fn create_parent(path: &Path) -> Result<(), Error> {
+    if let Some(parent) = path.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    Ok(())
+}
+
+#[cfg(not(feature = "blocking"))]
+impl Transport for CassetteTransport {
+    fn get(&self, url: Url, headers: HeaderMap) -> TransportFuture<'_> {
+        Box::pin(async move {
+            if let Some(response) = self.load(&url)? {
+                return Ok(response);
+            }
+
+            let response = self.inner(&url)?.get(url.clone(), headers).await?;
+            self.save_if_recorded(&url, &response)?;
+
+            Ok(response)
+        })
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl Transport for CassetteTransport {
+    fn get(&self, url: Url, headers: HeaderMap) -> Result<HttpResponse, Error> {
+        if let Some(response) = self.load(&url)? {
+            return Ok(response);
+        }
+
+        let response = self.inner(&url)?.get(url.clone(), headers)?;
+        self.save_if_recorded(&url, &response)?;
+
+        Ok(response)
+    }
+}
@@ -0,0 +1,100 @@
+//! Tolerant deserializers for values which the API represents
+//! inconsistently.
+//!
+//! Depending on the endpoint, booleans and numbers are returned either
+//! natively or as strings (`true` or `"true"`, `842` or `"842"`), and dates
+//! either as ISO 8601 strings or as objects with a timestamp in milliseconds
+//! and an ISO 8601 string. All of these functions accept both forms, and
+//! treat `null` and empty strings as missing values.
+
+use chrono::{DateTime, Utc};
+use serde::de::{Error, Unexpected};
+use serde::{Deserialize, Deserializer};
+use std::str::FromStr;
+
+/// Convert a kebab-case element type, e.g. `rich-link`, to camel case.
+pub(crate) fn camel_case(value: &str) -> String {
+    let mut words = value.split('-');
+    let mut result = words.next().unwrap_or_default().to_owned();
+    for word in words {
+        let mut chars = word.chars();
+        result.extend(chars.next().map(|c| c.to_ascii_uppercase()));
+        result.push_str(chars.as_str());
+    }
+    result
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Native<T> {
+    Value(T),
+    Text(String),
+}
+
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum Date {
+    Text(String),
+    #[serde(rename_all = "camelCase")]
+    Capi {
+        date_time: i64,
+        iso8601: Option<String>,
+    },
+}
+
+pub(crate) fn option_bool<'de, D>(deserializer: D) -> Result<Option<bool>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<Native<bool>>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(Native::Value(value)) => Ok(Some(value)),
+        Some(Native::Text(text)) => match text.trim() {
+            "" => Ok(None),
+            "true" => Ok(Some(true)),
+            "false" => Ok(Some(false)),
+            other => Err(D::Error::invalid_value(
+                Unexpected::Str(other),
+                &"a boolean",
+            )),
+        },
+    }
+}
+
+pub(crate) fn option_number<'de, D, T>(deserializer: D) -> Result<Option<T>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de> + FromStr,
+{
+    match Option::<Native<T>>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(Native::Value(value)) => Ok(Some(value)),
+        Some(Native::Text(text)) => match text.trim() {
+            "" => Ok(None),
+            other => other
+                .parse()
+                .map(Some)
+                .map_err(|_| D::Error::invalid_value(Unexpected::Str(other), &"a number")),
+        },
+    }
+}
+
+pub(crate) fn option_datetime<'de, D>(deserializer: D) -> Result<Option<DateTime<Utc>>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    match Option::<Date>::deserialize(deserializer)? {
+        None => Ok(None),
+        Some(Date::Text(text)) if text.trim().is_empty() => Ok(None),
+        Some(Date::Text(text))
+        | Some(Date::Capi {
+            iso8601: Some(text),
+            ..
+        }) => DateTime::parse_from_rfc3339(text.trim())
+            .map(|date| Some(date.with_timezone(&Utc)))
+            .map_err(|_| D::Error::invalid_value(Unexpected::Str(&text), &"an ISO 8601 date")),
+        Some(Date::Capi { date_time, .. }) => DateTime::from_timestamp_millis(date_time)
+            .map(Some)
+            .ok_or_else(|| D::Error::invalid_value(Unexpected::Signed(date_time), &"a timestamp")),
+    }
+}
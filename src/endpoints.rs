@@ -0,0 +1,387 @@
+//! Endpoint-specific request builders.
+//!
+//! [`GuardianRequestBuilder::send`] always deserializes responses into a
+//! [`SearchResponse`](crate::structs::SearchResponse), which only fits the
+//! content search endpoint. The builders in this module are created with
+//! [`GuardianContentClient::tags`](crate::GuardianContentClient::tags),
+//! [`GuardianContentClient::sections`](crate::GuardianContentClient::sections),
+//! [`GuardianContentClient::editions`](crate::GuardianContentClient::editions),
+//! [`GuardianContentClient::item`](crate::GuardianContentClient::item)
+//! and [`GuardianContentClient::atom`](crate::GuardianContentClient::atom),
+//! only expose the query parameters supported by their endpoint, and
+//! deserialize responses into the matching type from [`crate::structs`].
+
+use crate::enums::{
+    self, AtomType, ElementType, Field, OrderBy, OrderDate, ReferenceType, ReferenceTypeFilter,
+    TagType,
+};
+use crate::error::Error;
+use crate::ids::{Expression, SectionId};
+use crate::structs::{
+    AtomResponse, EditionsResponse, ItemResponse, SectionsResponse, TagsResponse,
+};
+use crate::GuardianRequestBuilder;
+
+/// A request to the [`Endpoint::Tags`](crate::enums::Endpoint::Tags) endpoint.
+///
+/// # Async example
+/// ```ignore
+/// let response = client
+///         .tags()
+///         .web_title("food")
+///         .tag_type(TagType::Keyword)
+///         .show_references(vec![ReferenceType::All])
+///         .send()
+///         .await?;
+///
+/// for tag in response.results.unwrap_or_default() {
+///     println!("{}: {:?}", tag.web_title, tag.description);
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct TagsRequestBuilder {
+    inner: GuardianRequestBuilder,
+}
+
+impl TagsRequestBuilder {
+    pub(crate) fn new(inner: GuardianRequestBuilder) -> Self {
+        Self { inner }
+    }
+
+    /// Return only tags matching a search query.
+    /// See [`GuardianRequestBuilder::search`].
+    pub fn search(mut self, q: &str) -> Self {
+        self.inner = self.inner.search(q);
+        self
+    }
+
+    /// See [`GuardianRequestBuilder::page`].
+    pub fn page(mut self, page: u32) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// See [`GuardianRequestBuilder::page_size`].
+    pub fn page_size(mut self, page_size: u8) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// Return only tags whose title matches a search query. Unlike
+    /// [`TagsRequestBuilder::search`], which also matches the id of tags,
+    /// only the title displayed on theguardian.com is searched.
+    pub fn web_title(mut self, web_title: &str) -> Self {
+        self.inner = self.inner.param("web-title", web_title);
+        self
+    }
+
+    /// Return only tags of the specified type.
+    pub fn tag_type(mut self, tag_type: TagType) -> Self {
+        self.inner = self.inner.param("type", tag_type);
+        self
+    }
+
+    /// Return only tags in those sections.
+    pub fn section(mut self, section: impl Into<Expression<SectionId>>) -> Self {
+        self.inner = self.inner.section(section);
+        self
+    }
+
+    /// Return only tags with those references.
+    pub fn reference(mut self, reference: &str) -> Self {
+        self.inner = self.inner.reference(reference);
+        self
+    }
+
+    /// Return only tags with references of this type.
+    pub fn reference_type(mut self, reference_type: ReferenceTypeFilter) -> Self {
+        self.inner = self.inner.reference_type(&reference_type.to_string());
+        self
+    }
+
+    /// Add the references of the specified types to
+    /// [`Tag::references`](crate::structs::Tag::references).
+    ///
+    /// If [`ReferenceType::All`] is included in the vector,
+    /// it will override all other reference types.
+    pub fn show_references(mut self, show_references: Vec<ReferenceType>) -> Self {
+        let reference_sequence = crate::helpers::generate_sequence(show_references);
+        self.inner = self.inner.param("show-references", reference_sequence);
+        self
+    }
+
+    /// Terminal operation that sends a GET request to the Guardian API.
+    /// Once this function is called, all the query parameters constructed
+    /// via the building methods are dropped.
+    #[cfg(not(feature = "blocking"))]
+    pub async fn send(&mut self) -> Result<TagsResponse, Error> {
+        self.inner.send_as().await
+    }
+
+    /// Terminal operation that sends a GET request to the Guardian API.
+    /// Once this function is called, all the query parameters constructed
+    /// via the building methods are dropped.
+    #[cfg(feature = "blocking")]
+    pub fn send(&mut self) -> Result<TagsResponse, Error> {
+        self.inner.send_as()
+    }
+}
+
+/// A request to the [`Endpoint::Sections`](crate::enums::Endpoint::Sections) endpoint.
+///
+/// # Async example
+/// ```ignore
+/// let response = client
+///         .sections()
+///         .search("business")
+///         .send()
+///         .await?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct SectionsRequestBuilder {
+    inner: GuardianRequestBuilder,
+}
+
+impl SectionsRequestBuilder {
+    pub(crate) fn new(inner: GuardianRequestBuilder) -> Self {
+        Self { inner }
+    }
+
+    /// Return only sections matching a search query.
+    /// See [`GuardianRequestBuilder::search`].
+    pub fn search(mut self, q: &str) -> Self {
+        self.inner = self.inner.search(q);
+        self
+    }
+
+    /// Terminal operation that sends a GET request to the Guardian API.
+    /// Once this function is called, all the query parameters constructed
+    /// via the building methods are dropped.
+    #[cfg(not(feature = "blocking"))]
+    pub async fn send(&mut self) -> Result<SectionsResponse, Error> {
+        self.inner.send_as().await
+    }
+
+    /// Terminal operation that sends a GET request to the Guardian API.
+    /// Once this function is called, all the query parameters constructed
+    /// via the building methods are dropped.
+    #[cfg(feature = "blocking")]
+    pub fn send(&mut self) -> Result<SectionsResponse, Error> {
+        self.inner.send_as()
+    }
+}
+
+/// A request to the [`Endpoint::Editions`](crate::enums::Endpoint::Editions) endpoint.
+///
+/// # Async example
+/// ```ignore
+/// let response = client
+///         .editions()
+///         .send()
+///         .await?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct EditionsRequestBuilder {
+    inner: GuardianRequestBuilder,
+}
+
+impl EditionsRequestBuilder {
+    pub(crate) fn new(inner: GuardianRequestBuilder) -> Self {
+        Self { inner }
+    }
+
+    /// Return only editions matching a search query.
+    /// See [`GuardianRequestBuilder::search`].
+    pub fn search(mut self, q: &str) -> Self {
+        self.inner = self.inner.search(q);
+        self
+    }
+
+    /// Terminal operation that sends a GET request to the Guardian API.
+    /// Once this function is called, all the query parameters constructed
+    /// via the building methods are dropped.
+    #[cfg(not(feature = "blocking"))]
+    pub async fn send(&mut self) -> Result<EditionsResponse, Error> {
+        self.inner.send_as().await
+    }
+
+    /// Terminal operation that sends a GET request to the Guardian API.
+    /// Once this function is called, all the query parameters constructed
+    /// via the building methods are dropped.
+    #[cfg(feature = "blocking")]
+    pub fn send(&mut self) -> Result<EditionsResponse, Error> {
+        self.inner.send_as()
+    }
+}
+
+/// A request for a single item, i.e. a piece of content, a tag,
+/// a section or an edition.
+///
+/// The paging and ordering parameters apply to the list of content
+/// returned along with a tag, a section or an edition.
+///
+/// # Async example
+/// ```ignore
+/// let response = client
+///         .item("technology/apple")
+///         .page_size(5)
+///         .order_by(OrderBy::Newest)
+///         .show_editors_picks(true)
+///         .send()
+///         .await?;
+///
+/// println!("{:?}", response.tag.map(|tag| tag.web_title));
+/// println!("{:#?}", response.editors_picks);
+/// ```
+#[derive(Debug, Clone)]
+pub struct ItemRequestBuilder {
+    inner: GuardianRequestBuilder,
+}
+
+impl ItemRequestBuilder {
+    pub(crate) fn new(inner: GuardianRequestBuilder) -> Self {
+        Self { inner }
+    }
+
+    /// See [`GuardianRequestBuilder::show_fields`].
+    pub fn show_fields(mut self, show_fields: Vec<Field>) -> Self {
+        self.inner = self.inner.show_fields(show_fields);
+        self
+    }
+
+    /// See [`GuardianRequestBuilder::show_tags`].
+    pub fn show_tags(mut self, show_tags: Vec<enums::Tag>) -> Self {
+        self.inner = self.inner.show_tags(show_tags);
+        self
+    }
+
+    /// See [`GuardianRequestBuilder::show_blocks`].
+    pub fn show_blocks(mut self, show_blocks: Vec<enums::Block>) -> Self {
+        self.inner = self.inner.show_blocks(show_blocks);
+        self
+    }
+
+    /// See [`GuardianRequestBuilder::show_atoms`].
+    pub fn show_atoms(mut self, show_atoms: Vec<AtomType>) -> Self {
+        self.inner = self.inner.show_atoms(show_atoms);
+        self
+    }
+
+    /// See [`GuardianRequestBuilder::show_elements`].
+    pub fn show_elements(mut self, show_elements: Vec<ElementType>) -> Self {
+        self.inner = self.inner.show_elements(show_elements);
+        self
+    }
+
+    /// See [`GuardianRequestBuilder::show_rights`].
+    pub fn show_rights(mut self, show_rights: bool) -> Self {
+        self.inner = self.inner.show_rights(show_rights);
+        self
+    }
+
+    /// See [`GuardianRequestBuilder::show_section`].
+    pub fn show_section(mut self, show_section: bool) -> Self {
+        self.inner = self.inner.show_section(show_section);
+        self
+    }
+
+    /// Add content related to the item to
+    /// [`ItemResponse::related_content`].
+    pub fn show_related(mut self, show_related: bool) -> Self {
+        self.inner = self.inner.param("show-related", show_related);
+        self
+    }
+
+    /// Add the story package the item belongs to, if any, to
+    /// [`ItemResponse::story_package`].
+    pub fn show_story_package(mut self, show_story_package: bool) -> Self {
+        self.inner = self.inner.param("show-story-package", show_story_package);
+        self
+    }
+
+    /// Add the content picked by editors for a tag or a section to
+    /// [`ItemResponse::editors_picks`].
+    pub fn show_editors_picks(mut self, show_editors_picks: bool) -> Self {
+        self.inner = self.inner.param("show-editors-picks", show_editors_picks);
+        self
+    }
+
+    /// Add the most viewed content of a tag or a section to
+    /// [`ItemResponse::most_viewed`].
+    pub fn show_most_viewed(mut self, show_most_viewed: bool) -> Self {
+        self.inner = self.inner.param("show-most-viewed", show_most_viewed);
+        self
+    }
+
+    /// See [`GuardianRequestBuilder::page`].
+    pub fn page(mut self, page: u32) -> Self {
+        self.inner = self.inner.page(page);
+        self
+    }
+
+    /// See [`GuardianRequestBuilder::page_size`].
+    pub fn page_size(mut self, page_size: u8) -> Self {
+        self.inner = self.inner.page_size(page_size);
+        self
+    }
+
+    /// See [`GuardianRequestBuilder::order_by`].
+    pub fn order_by(mut self, order_by: OrderBy) -> Self {
+        self.inner = self.inner.order_by(order_by);
+        self
+    }
+
+    /// See [`GuardianRequestBuilder::order_date`].
+    pub fn order_date(mut self, order_date: OrderDate) -> Self {
+        self.inner = self.inner.order_date(order_date);
+        self
+    }
+
+    /// Terminal operation that sends a GET request to the Guardian API.
+    /// Once this function is called, all the query parameters constructed
+    /// via the building methods are dropped.
+    #[cfg(not(feature = "blocking"))]
+    pub async fn send(&mut self) -> Result<ItemResponse, Error> {
+        self.inner.send_as().await
+    }
+
+    /// Terminal operation that sends a GET request to the Guardian API.
+    /// Once this function is called, all the query parameters constructed
+    /// via the building methods are dropped.
+    #[cfg(feature = "blocking")]
+    pub fn send(&mut self) -> Result<ItemResponse, Error> {
+        self.inner.send_as()
+    }
+}
+
+/// A request for a single content atom.
+///
+/// # Async example
+/// ```ignore
+/// let response = client
+///         .atom(AtomLookupType::Quiz, "2c7c8a52-0d4e-4a7b-8a3b-6f0e1d2c3b4a")
+///         .send()
+///         .await?;
+/// ```
+#[derive(Debug, Clone)]
+pub struct AtomRequestBuilder {
+    inner: GuardianRequestBuilder,
+}
+
+impl AtomRequestBuilder {
+    pub(crate) fn new(inner: GuardianRequestBuilder) -> Self {
+        Self { inner }
+    }
+
+    /// Terminal operation that sends a GET request to the Guardian API.
+    #[cfg(not(feature = "blocking"))]
+    pub async fn send(&mut self) -> Result<AtomResponse, Error> {
+        self.inner.send_as().await
+    }
+
+    /// Terminal operation that sends a GET request to the Guardian API.
+    #[cfg(feature = "blocking")]
+    pub fn send(&mut self) -> Result<AtomResponse, Error> {
+        self.inner.send_as()
+    }
+}
@@ -30,6 +30,7 @@ pub enum UseDate {
 }
 
 #[derive(Clone, Display, Debug, Deserialize, Eq, PartialEq)]
+#[cfg_attr(test, derive(strum_macros::EnumIter))]
 #[strum(serialize_all = "camelCase")]
 pub enum Field {
     TrailText,
@@ -55,24 +56,111 @@ pub enum Field {
     LiveBloggingNow,
     CommentCloseDate,
     StarRating,
+    Main,
+    NewspaperPageNumber,
+    ContributorBio,
+    MembershipAccess,
+    DisplayHint,
+    FirstPublicationDate,
+    IsInappropriateForSponsorship,
+    NewspaperEditionDate,
+    ScheduledPublicationDate,
+    LegallySensitive,
+    Sensitive,
+    Lang,
+    IsLive,
+    InternalShortId,
+    ShortSocialShareText,
+    SocialShareText,
+    CharCount,
+    InternalVideoCode,
+    ShouldHideReaderRevenue,
+    ShowAffiliateLinks,
+    BylineHtml,
     /// Override all fields
     All,
 }
 
-#[derive(Clone, Display, Debug, Deserialize, Eq, PartialEq)]
-#[strum(serialize_all = "kebab-case")]
-pub enum Tag {
-    Blog,
-    Contributor,
-    Keyword,
-    NewspaperBook,
-    NewspaperBookSection,
-    Publication,
-    Series,
-    Tone,
-    Type,
-    /// Override all tags
-    All,
+/// Define two enums with the same kebab-case variants: one with an extra
+/// `All` variant, to request everything the API can show, and one without,
+/// for filters and lookups where `All` would be meaningless.
+macro_rules! with_and_without_all {
+    (
+        $(#[$with_all_meta:meta])*
+        $with_all:ident, $all_doc:literal,
+        $(#[$without_all_meta:meta])*
+        $without_all:ident {
+            $($(#[$variant_meta:meta])* $variant:ident,)*
+        }
+    ) => {
+        $(#[$with_all_meta])*
+        #[derive(Clone, Display, Debug, Deserialize, Eq, PartialEq)]
+        #[strum(serialize_all = "kebab-case")]
+        pub enum $with_all {
+            $($(#[$variant_meta])* $variant,)*
+            #[doc = $all_doc]
+            All,
+        }
+
+        $(#[$without_all_meta])*
+        #[derive(Clone, Display, Debug, Deserialize, Eq, PartialEq)]
+        #[strum(serialize_all = "kebab-case")]
+        pub enum $without_all {
+            $($(#[$variant_meta])* $variant,)*
+        }
+
+        impl From<$without_all> for $with_all {
+            fn from(value: $without_all) -> Self {
+                match value {
+                    $($without_all::$variant => $with_all::$variant,)*
+                }
+            }
+        }
+    };
+}
+
+with_and_without_all! {
+    Tag, "Override all tags",
+    /// The type of a tag, used to filter the results of the tags endpoint.
+    /// It has the same variants as [`Tag`], except `All`.
+    TagType {
+        Blog,
+        Contributor,
+        Keyword,
+        NewspaperBook,
+        NewspaperBookSection,
+        Publication,
+        Series,
+        Tone,
+        Type,
+    }
+}
+
+with_and_without_all! {
+    /// The kind of an external reference attached to a tag or a piece of content.
+    ReferenceType, "Override all reference types",
+    /// The kind of an external reference, used to filter the results
+    /// of the tags endpoint. It has the same variants as [`ReferenceType`],
+    /// except `All`.
+    ReferenceTypeFilter {
+        Isbn,
+        Imdb,
+        Musicbrainz,
+        #[strum(serialize = "musicbrainzgenre")]
+        MusicbrainzGenre,
+        BisacPrefix,
+        EsaCricketMatch,
+        EsaFootballTeam,
+        EsaFootballTournament,
+        OptaFootballMatch,
+        OptaFootballTeam,
+        OptaFootballTournament,
+        PaFootballCompetition,
+        PaFootballMatch,
+        PaFootballPlayer,
+        PaFootballTeam,
+        WitnessAssignment,
+    }
 }
 
 #[derive(Clone, Display, Debug, Deserialize, Eq, PartialEq)]
@@ -93,7 +181,47 @@ pub enum Block<'a> {
     BodyPublishedSince(i64),
 }
 
-#[derive(Clone, Display, Default, Debug, Deserialize, Eq, PartialEq)]
+#[derive(Clone, Display, Debug, Deserialize, Eq, PartialEq)]
+#[strum(serialize_all = "kebab-case")]
+pub enum ElementType {
+    Audio,
+    Image,
+    Video,
+    /// Override all element types
+    All,
+}
+
+/// A kind of reuse of content, used to only return content
+/// which can be reused in that way.
+#[derive(Clone, Copy, Display, Debug, Deserialize, Eq, PartialEq)]
+#[strum(serialize_all = "kebab-case")]
+pub enum RightsFilter {
+    Syndicatable,
+    SubscriptionDatabases,
+    DeveloperCommunity,
+}
+
+with_and_without_all! {
+    AtomType, "Override all atom types",
+    /// The type of a single atom looked up with
+    /// [`GuardianContentClient::atom`](crate::GuardianContentClient::atom).
+    /// It has the same variants as [`AtomType`], except `All`.
+    AtomLookupType {
+        Quiz,
+        Explainer,
+        Qanda,
+        Timeline,
+        Profile,
+        Guide,
+        Media,
+        Review,
+        Chart,
+        Audio,
+        Recipe,
+    }
+}
+
+#[derive(Clone, Display, Default, Debug, Deserialize, Eq, Hash, PartialEq)]
 #[strum(serialize_all = "kebab-case")]
 pub enum Endpoint {
     #[default]
@@ -129,3 +257,6 @@ macro_rules! impl_is_all {
 impl_is_all!(Block<'a>);
 impl_is_all!(Field);
 impl_is_all!(Tag);
+impl_is_all!(ReferenceType);
+impl_is_all!(AtomType);
+impl_is_all!(ElementType);
@@ -12,4 +12,18 @@ pub enum Error {
     MissingQueryParameter(&'static str),
     #[error("API error: {0}")]
     ApiError(String),
+    #[error("Invalid base URL: {0}")]
+    InvalidBaseUrl(String),
+    #[error("Rate limit exceeded, next request allowed in {0:?}")]
+    RateLimitExceeded(std::time::Duration),
+    #[error("Daily quota of {0} requests exhausted")]
+    QuotaExhausted(u32),
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+    #[error(transparent)]
+    JsonError(#[from] serde_json::Error),
+    #[error("No recorded response for {0}")]
+    MissingCassette(String),
+    #[error("Invalid id: {0}")]
+    InvalidId(String),
 }
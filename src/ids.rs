@@ -0,0 +1,488 @@
+//! Identifiers of content, tags, sections and editions.
+//!
+//! Each kind of identifier has its own type, so that e.g. a tag id cannot be
+//! passed where a section id is expected. Ids are validated when parsed with
+//! [`str::parse`] or created from a URL with `from_url`:
+//! - [`ContentId`]: a path of at least two segments, e.g.
+//!   `world/2022/jan/01/funeral-of-desmond-tutu-takes-place-in-cape-town`
+//! - [`TagId`]: a section and one or more slugs, e.g. `technology/apple`
+//!   or `tracking/commissioningdesk/uk-news`
+//! - [`SectionId`] and [`EditionId`]: a single slug, e.g. `football` or `uk`
+//!
+//! `from_url` extracts an id from a link to <https://www.theguardian.com>,
+//! a URL of the API or a bare path, ignoring query strings and fragments.
+//!
+//! Builder methods which filter by ids take an [`Expression`], created
+//! either from a single id or from a `&str` combining several ids with the
+//! operators of the API, e.g. `"football|-sport"`.
+//! [`GuardianContentClient::item`](crate::GuardianContentClient::item)
+//! takes an [`ItemId`], created from any of the ids or from a `&str`.
+//! A `&str` which is not valid makes the request fail with
+//! [`Error::InvalidId`] when it is sent; parse it first to handle the
+//! error upfront.
+//!
+//! # Example
+//! ```ignore
+//! let tag: TagId = "technology/apple".parse()?;
+//! let section = SectionId::from_url("https://www.theguardian.com/football")?;
+//!
+//! let response = client
+//!         .build_request()
+//!         .tag(tag)
+//!         .section(section)
+//!         .send()
+//!         .await?;
+//!
+//! let response = client
+//!         .build_request()
+//!         .tag("technology/apple,-type/video")
+//!         .send()
+//!         .await?;
+//! ```
+
+use crate::error::Error;
+use reqwest::Url;
+use serde::Deserialize;
+#[cfg(feature = "serde-serialize")]
+use serde::Serialize;
+use std::borrow::Borrow;
+use std::fmt::{self, Display};
+use std::marker::PhantomData;
+use std::ops::Deref;
+use std::str::FromStr;
+
+/// Hosts of the URLs from which ids can be extracted.
+const GUARDIAN_HOSTS: [&str; 4] = [
+    "www.theguardian.com",
+    "theguardian.com",
+    "amp.theguardian.com",
+    "content.guardianapis.com",
+];
+
+macro_rules! impl_id {
+    ($id_type:ident, $description:literal, $is_valid:expr) => {
+        impl $id_type {
+            /// The id as a string slice.
+            pub fn as_str(&self) -> &str {
+                &self.0
+            }
+
+            /// Extract the id from a `web_url`, e.g.
+            /// `https://www.theguardian.com/football`, an `api_url`, e.g.
+            /// `https://content.guardianapis.com/football`, or a bare path.
+            ///
+            /// Query strings, fragments and surrounding slashes are removed.
+            /// URLs of other hosts are rejected with [`Error::InvalidId`].
+            pub fn from_url(url: &str) -> Result<Self, Error> {
+                guardian_path(url)
+                    .ok_or_else(|| Error::InvalidId(url.to_owned()))?
+                    .parse()
+            }
+        }
+
+        impl FromStr for $id_type {
+            type Err = Error;
+
+            /// Parse an id, returning [`Error::InvalidId`]
+            #[doc = concat!("if it is not ", $description, ".")]
+            fn from_str(value: &str) -> Result<Self, Self::Err> {
+                let is_valid: fn(&str) -> bool = $is_valid;
+
+                if is_valid(value) {
+                    Ok(Self(value.to_owned()))
+                } else {
+                    Err(Error::InvalidId(value.to_owned()))
+                }
+            }
+        }
+
+        impl From<&$id_type> for $id_type {
+            fn from(value: &$id_type) -> Self {
+                value.clone()
+            }
+        }
+
+        impl From<$id_type> for String {
+            fn from(value: $id_type) -> Self {
+                value.0
+            }
+        }
+
+        impl Deref for $id_type {
+            type Target = str;
+
+            fn deref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl AsRef<str> for $id_type {
+            fn as_ref(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl Borrow<str> for $id_type {
+            fn borrow(&self) -> &str {
+                &self.0
+            }
+        }
+
+        impl Display for $id_type {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str(&self.0)
+            }
+        }
+
+        impl PartialEq<str> for $id_type {
+            fn eq(&self, other: &str) -> bool {
+                self.0 == other
+            }
+        }
+
+        impl PartialEq<&str> for $id_type {
+            fn eq(&self, other: &&str) -> bool {
+                self.0 == *other
+            }
+        }
+
+        impl From<$id_type> for ItemId {
+            fn from(value: $id_type) -> Self {
+                Self(Ok(value.0))
+            }
+        }
+
+        impl From<&$id_type> for ItemId {
+            fn from(value: &$id_type) -> Self {
+                Self(Ok(value.0.clone()))
+            }
+        }
+
+        impl From<$id_type> for Expression<$id_type> {
+            fn from(value: $id_type) -> Self {
+                Self::new(value.0)
+            }
+        }
+
+        impl From<&$id_type> for Expression<$id_type> {
+            fn from(value: &$id_type) -> Self {
+                Self::new(value.0.clone())
+            }
+        }
+
+        /// Validated when the request is sent. See [`Expression`].
+        impl From<&str> for Expression<$id_type> {
+            fn from(value: &str) -> Self {
+                Self::parse(value.to_owned())
+            }
+        }
+
+        /// Validated when the request is sent. See [`Expression`].
+        impl From<String> for Expression<$id_type> {
+            fn from(value: String) -> Self {
+                Self::parse(value)
+            }
+        }
+    };
+}
+
+/// Tag ids are made of a section and one or more slugs, so they are also
+/// valid content ids.
+impl From<TagId> for ContentId {
+    fn from(value: TagId) -> Self {
+        Self(value.0)
+    }
+}
+
+/// A query expression over ids of type `T`, such as the value of the `tag`,
+/// `section` or `ids` parameters.
+///
+/// It is either a single id, several ids collected from an iterator, which
+/// are joined with commas, or a `&str` or `String` combining several ids
+/// with the operators of the API: `,` (and), `|` (or), a leading `-` (not)
+/// and parentheses, e.g. `"technology/apple,-(type/video|type/audio)"`.
+///
+/// Each id of a string must be a valid `T`. Parsing the string with
+/// [`str::parse`] returns [`Error::InvalidId`] if it is not, while
+/// converting it with [`From`] defers the error to when the request
+/// is sent.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Expression<T> {
+    value: Result<String, String>,
+    id_type: PhantomData<fn() -> T>,
+}
+
+impl<T> Expression<T> {
+    fn new(value: String) -> Self {
+        Self {
+            value: Ok(value),
+            id_type: PhantomData,
+        }
+    }
+
+    /// The expression as a string slice.
+    pub fn as_str(&self) -> &str {
+        match &self.value {
+            Ok(value) | Err(value) => value,
+        }
+    }
+
+    /// The expression to send, or the string it was created from if it
+    /// is not valid.
+    pub(crate) fn into_param(self) -> Result<String, String> {
+        self.value
+    }
+}
+
+impl<T: FromStr> Expression<T> {
+    fn parse(value: String) -> Self {
+        Self {
+            value: if is_expression::<T>(&value) {
+                Ok(value)
+            } else {
+                Err(value)
+            },
+            id_type: PhantomData,
+        }
+    }
+}
+
+impl<T: FromStr> FromStr for Expression<T> {
+    type Err = Error;
+
+    /// Parse an expression, returning [`Error::InvalidId`] if it is
+    /// malformed or if any of its ids is not a valid `T`.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::parse(value.to_owned())
+            .value
+            .map(Self::new)
+            .map_err(Error::InvalidId)
+    }
+}
+
+impl<T: Into<String>> FromIterator<T> for Expression<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(ids: I) -> Self {
+        Self::new(
+            ids.into_iter()
+                .map(Into::into)
+                .collect::<Vec<_>>()
+                .join(","),
+        )
+    }
+}
+
+impl<T> Display for Expression<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The id of an item looked up with
+/// [`GuardianContentClient::item`](crate::GuardianContentClient::item):
+/// a piece of content, a tag, a section or an edition.
+///
+/// It is created from a [`ContentId`], a [`TagId`], a [`SectionId`] or an
+/// [`EditionId`], or from a `&str` or `String`. Parsing a string with
+/// [`str::parse`] returns [`Error::InvalidId`] if it is not a valid id of
+/// any kind, while converting it with [`From`] defers the error to when
+/// the request is sent.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct ItemId(Result<String, String>);
+
+impl ItemId {
+    /// The id as a string slice.
+    pub fn as_str(&self) -> &str {
+        match &self.0 {
+            Ok(value) | Err(value) => value,
+        }
+    }
+
+    /// Extract the id from a `web_url`, an `api_url` or a bare path.
+    /// See [`ContentId::from_url`] for details.
+    pub fn from_url(url: &str) -> Result<Self, Error> {
+        guardian_path(url)
+            .ok_or_else(|| Error::InvalidId(url.to_owned()))?
+            .parse()
+    }
+
+    /// The path of the item, or the string it was created from if it
+    /// is not valid.
+    pub(crate) fn into_path(self) -> Result<String, String> {
+        self.0
+    }
+}
+
+impl FromStr for ItemId {
+    type Err = Error;
+
+    /// Parse an id, returning [`Error::InvalidId`] if it is not a valid
+    /// content, tag, section or edition id.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::from(value)
+            .0
+            .map(|value| Self(Ok(value)))
+            .map_err(Error::InvalidId)
+    }
+}
+
+/// Validated when the request is sent. See [`ItemId`].
+impl From<&str> for ItemId {
+    fn from(value: &str) -> Self {
+        Self::from(value.to_owned())
+    }
+}
+
+/// Validated when the request is sent. See [`ItemId`].
+impl From<String> for ItemId {
+    fn from(value: String) -> Self {
+        // Tag ids are also valid content ids
+        if value.parse::<ContentId>().is_ok() || is_slug(&value) {
+            Self(Ok(value))
+        } else {
+            Self(Err(value))
+        }
+    }
+}
+
+impl Display for ItemId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// The id of a piece of content.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(transparent)]
+pub struct ContentId(String);
+
+/// The id of a tag.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(transparent)]
+pub struct TagId(String);
+
+/// The id of a section.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(transparent)]
+pub struct SectionId(String);
+
+/// The id of an edition.
+#[derive(Clone, Debug, Deserialize, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(transparent)]
+pub struct EditionId(String);
+
+impl_id!(ContentId, "a path of at least two segments", |value| {
+    value.split('/').count() >= 2 && value.split('/').all(is_segment)
+});
+impl_id!(
+    TagId,
+    "a section and one or more slugs separated by slashes",
+    |value| { value.split('/').count() >= 2 && value.split('/').all(is_slug) }
+);
+impl_id!(SectionId, "a single slug", is_slug);
+impl_id!(EditionId, "a single slug", is_slug);
+
+/// Whether `value` combines valid ids of type `T` with the operators of the
+/// API: `,` (and), `|` (or), a leading `-` (not) and parentheses.
+fn is_expression<T: FromStr>(value: &str) -> bool {
+    let mut tokens = Vec::new();
+    let mut start = 0;
+    for (index, c) in value.char_indices() {
+        if matches!(c, ',' | '|' | '(' | ')') {
+            tokens.push(&value[start..index]);
+            tokens.push(&value[index..index + 1]);
+            start = index + 1;
+        }
+    }
+    tokens.push(&value[start..]);
+
+    let mut depth = 0_usize;
+    let mut expects_operand = true;
+    for token in tokens
+        .into_iter()
+        .map(str::trim)
+        .filter(|token| !token.is_empty())
+    {
+        match token {
+            "(" if expects_operand => depth += 1,
+            ")" if !expects_operand && depth > 0 => depth -= 1,
+            "," | "|" if !expects_operand => expects_operand = true,
+            "(" | ")" | "," | "|" => return false,
+            // A negated group, e.g. `-(type/video|type/audio)`
+            "-" if expects_operand => {}
+            operand if expects_operand => {
+                let id = operand.strip_prefix('-').unwrap_or(operand);
+                if id.parse::<T>().is_err() {
+                    return false;
+                }
+                expects_operand = false;
+            }
+            _ => return false,
+        }
+    }
+
+    depth == 0 && !expects_operand
+}
+
+/// Whether `value` is a non-empty segment of a path, without
+/// characters that would change the meaning of a URL.
+fn is_segment(value: &str) -> bool {
+    !value.is_empty()
+        && !value
+            .chars()
+            .any(|c| c.is_whitespace() || matches!(c, '?' | '#' | '%' | '\\'))
+}
+
+/// Whether `value` is made of lowercase letters, digits and hyphens.
+fn is_slug(value: &str) -> bool {
+    !value.is_empty()
+        && value
+            .chars()
+            .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-')
+}
+
+/// The path of a Guardian URL, with or without a scheme, or of a bare path,
+/// without its query string, fragment and surrounding slashes.
+fn guardian_path(url: &str) -> Option<String> {
+    let url = url.trim();
+
+    let has_guardian_host = GUARDIAN_HOSTS.iter().any(|host| {
+        url.strip_prefix(host)
+            .is_some_and(|rest| rest.is_empty() || rest.starts_with(['/', '?', '#']))
+    });
+
+    let path = if url.contains("://") || has_guardian_host {
+        let parsed = match Url::parse(url) {
+            Ok(parsed) => parsed,
+            Err(_) => Url::parse(&format!("https://{url}")).ok()?,
+        };
+
+        let is_guardian_url = matches!(parsed.scheme(), "http" | "https")
+            && parsed
+                .host_str()
+                .is_some_and(|host| GUARDIAN_HOSTS.contains(&host));
+        if !is_guardian_url {
+            return None;
+        }
+
+        parsed.path().to_owned()
+    } else {
+        let path = url.split(['?', '#']).next().unwrap_or_default();
+
+        // Segments never contain dots, so this is the host of another site
+        if path
+            .split('/')
+            .next()
+            .is_some_and(|segment| segment.contains('.'))
+        {
+            return None;
+        }
+
+        path.to_owned()
+    };
+
+    Some(path.trim_matches('/').to_owned())
+}
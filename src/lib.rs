@@ -68,18 +68,46 @@
 //! # }
 //! ```
 
+pub mod cache;
+pub mod cassette;
+mod de;
+pub mod endpoints;
 pub mod enums;
 pub mod error;
+pub mod ids;
+mod pagination;
+pub mod quota;
+pub mod rate_limit;
+pub mod retry;
+#[cfg(feature = "serde-serialize")]
+mod ser;
+pub mod strict;
 pub mod structs;
 mod tests;
+pub mod transport;
 
+use crate::cache::{Lookup, ResponseCache};
+use crate::endpoints::{
+    AtomRequestBuilder, EditionsRequestBuilder, ItemRequestBuilder, SectionsRequestBuilder,
+    TagsRequestBuilder,
+};
 use crate::enums::*;
 use crate::error::Error;
+use crate::ids::{ContentId, Expression, ItemId, SectionId, TagId};
+use crate::quota::QuotaTracker;
+use crate::rate_limit::{RateLimit, RateLimiter};
+use crate::retry::{Outcome, RetryPolicy};
+use crate::strict::UnknownMembers;
 use crate::structs::*;
+use crate::transport::{HttpResponse, ReqwestTransport, Transport};
 use reqwest::header::{HeaderMap, HeaderValue};
-use reqwest::Url;
+use reqwest::{Proxy, Url};
+use serde::de::DeserializeOwned;
+use serde_json::value::RawValue;
 use std::collections::HashMap;
 use std::string::ToString;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
 
 const GUARDIAN_CONTENT_API_URL: &str = "https://content.guardianapis.com";
 
@@ -109,18 +137,30 @@ type ReqwestClient = reqwest::blocking::Client;
     if it is not invoked in a blocking context (e.g. by using [`tokio::task::spawn_blocking`])"
 )]
 pub struct GuardianContentClient {
-    http_client: ReqwestClient,
+    transport: Arc<dyn Transport>,
     api_key: String,
     base_url: Url,
+    retry_policy: RetryPolicy,
+    rate_limiter: Option<RateLimiter>,
+    quota: Option<QuotaTracker>,
+    cache: Option<ResponseCache>,
+    strict: bool,
 }
 
 #[derive(Debug, Clone)]
 pub struct GuardianRequestBuilder {
-    http_client: ReqwestClient,
+    transport: Arc<dyn Transport>,
     base_url: Url,
     api_key: String,
     request: HashMap<String, String>,
     endpoint: Endpoint,
+    path: Option<String>,
+    retry_policy: RetryPolicy,
+    rate_limiter: Option<RateLimiter>,
+    quota: Option<QuotaTracker>,
+    cache: Option<ResponseCache>,
+    strict: bool,
+    invalid_id: Option<String>,
 }
 
 impl GuardianRequestBuilder {
@@ -137,6 +177,8 @@ impl GuardianRequestBuilder {
     /// - [`Endpoint::SingleItem`]: returns all the data for a given single item id.
     ///   Here the term 'item' refers to either a piece of content, a tag, or a section.
     ///   The item endpoint matches the paths on theguardian.com.
+    ///   Prefer [`GuardianContentClient::item`], which takes the id as a path
+    ///   rather than as a search query and returns a typed response.
     ///
     /// # Async example 1
     /// ```ignore
@@ -523,6 +565,11 @@ impl GuardianRequestBuilder {
 
     /// Return only content in those sections.
     ///
+    /// Takes a [`SectionId`], or a `&str` for expressions combining
+    /// several sections, e.g. `"football|-sport"`. See [`Expression`]:
+    /// if the expression is not valid, sending the request returns
+    /// [`Error::InvalidId`].
+    ///
     /// # Async example
     /// ```ignore
     /// let response = client
@@ -532,10 +579,8 @@ impl GuardianRequestBuilder {
     ///         .send()
     ///         .await?;
     /// ```
-    pub fn section(mut self, section: &str) -> GuardianRequestBuilder {
-        self.request
-            .insert(String::from("section"), section.to_string());
-        self
+    pub fn section(self, section: impl Into<Expression<SectionId>>) -> GuardianRequestBuilder {
+        self.expression("section", section.into())
     }
 
     /// Return only content with those references.
@@ -574,6 +619,11 @@ impl GuardianRequestBuilder {
 
     /// Return only content with those tags.
     ///
+    /// Takes a [`TagId`], or a `&str` for expressions combining
+    /// several tags, e.g. `"technology/apple,-type/video"`. See [`Expression`]:
+    /// if the expression is not valid, sending the request returns
+    /// [`Error::InvalidId`].
+    ///
     /// # Async example
     /// ```ignore
     /// let response = client
@@ -583,13 +633,16 @@ impl GuardianRequestBuilder {
     ///         .send()
     ///         .await?;
     /// ```
-    pub fn tag(mut self, tag: &str) -> GuardianRequestBuilder {
-        self.request.insert(String::from("tag"), tag.to_string());
-        self
+    pub fn tag(self, tag: impl Into<Expression<TagId>>) -> GuardianRequestBuilder {
+        self.expression("tag", tag.into())
     }
 
     /// Return only content with those IDs.
     ///
+    /// Takes a [`ContentId`], several ids collected into an [`Expression`],
+    /// or a `&str` of several ids separated by commas. If any of the ids
+    /// is not valid, sending the request returns [`Error::InvalidId`].
+    ///
     /// # Async example
     /// ```ignore
     /// let response = client
@@ -599,9 +652,8 @@ impl GuardianRequestBuilder {
     ///         .send()
     ///         .await?;
     /// ```
-    pub fn ids(mut self, ids: &str) -> GuardianRequestBuilder {
-        self.request.insert(String::from("ids"), ids.to_string());
-        self
+    pub fn ids(self, ids: impl Into<Expression<ContentId>>) -> GuardianRequestBuilder {
+        self.expression("ids", ids.into())
     }
 
     /// Return only content from those production offices.
@@ -686,14 +738,14 @@ impl GuardianRequestBuilder {
     /// - [`Block::Body`]
     /// - [`Block::All`]
     /// - [`Block::BodyLatest`] (limit defaults to 20)
-    /// - [`Block::BodyLatestWith(i32)`] (override the limits)
+    /// - [`Block::BodyLatestWith`] (override the limits)
     /// - [`Block::BodyOldest`]
-    /// - [`Block::BodyOldestWith(i32)`]
+    /// - [`Block::BodyOldestWith`]
     /// - [`Block::BodyBlockId(&'a str)`] (only the block with that ID)
     /// - [`Block::BodyAroundBlockId(&'a str)`] (the specified block and 20 blocks either side of it)
     /// - [`Block::BodyAroundBlockIdWith(&'a str, i32)`] (the specified block and n blocks either side of it)
     /// - [`Block::BodyKeyEvents`]
-    /// - [`Block::BodyPublishedSince(i64)`]  (only blocks since given timestamp)
+    /// - [`Block::BodyPublishedSince`]  (only blocks since given timestamp)
     ///
     /// # Async example
     /// ```ignore
@@ -712,61 +764,206 @@ impl GuardianRequestBuilder {
         self
     }
 
+    /// Add the atoms embedded in the content, such as quizzes or explainers.
+    ///
+    /// The function accepts a vector of [`enums::AtomType`] variants.
+    /// If [`AtomType::All`] is included in the vector, it will override
+    /// all other atom types.
+    ///
+    /// # Async example
+    /// ```ignore
+    /// let response = client
+    ///         .build_request()
+    ///         .search("Elections")
+    ///         .show_atoms(vec![AtomType::Explainer, AtomType::Quiz])
+    ///         .send()
+    ///         .await?;
+    /// ```
+    pub fn show_atoms(mut self, show_atoms: Vec<AtomType>) -> GuardianRequestBuilder {
+        let atom_sequence = crate::helpers::generate_sequence(show_atoms);
+        self.request
+            .insert(String::from("show-atoms"), atom_sequence);
+        self
+    }
+
+    /// Add the media elements of the content, such as images or videos,
+    /// along with all of their renditions.
+    ///
+    /// The function accepts a vector of [`enums::ElementType`] variants.
+    /// If [`ElementType::All`] is included in the vector, it will override
+    /// all other element types.
+    ///
+    /// # Async example
+    /// ```ignore
+    /// let response = client
+    ///         .build_request()
+    ///         .search("Elections")
+    ///         .show_elements(vec![ElementType::Image])
+    ///         .send()
+    ///         .await?;
+    /// ```
+    pub fn show_elements(mut self, show_elements: Vec<ElementType>) -> GuardianRequestBuilder {
+        let element_sequence = crate::helpers::generate_sequence(show_elements);
+        self.request
+            .insert(String::from("show-elements"), element_sequence);
+        self
+    }
+
+    /// Add the syndication rights of the content.
+    /// See also [`GuardianRequestBuilder::rights`] to filter content by rights.
+    ///
+    /// # Async example
+    /// ```ignore
+    /// let response = client
+    ///         .item("books/2022/jan/01/2022-in-books-highlights-for-the-year-ahead")
+    ///         .show_rights(true)
+    ///         .send()
+    ///         .await?;
+    ///
+    /// let syndicatable = response
+    ///         .content
+    ///         .and_then(|content| content.rights)
+    ///         .is_some_and(|rights| rights.syndicatable);
+    /// ```
+    pub fn show_rights(mut self, show_rights: bool) -> GuardianRequestBuilder {
+        if show_rights {
+            self.request
+                .insert(String::from("show-rights"), String::from("all"));
+        } else {
+            self.request.remove("show-rights");
+        }
+        self
+    }
+
+    /// Return only content which can be reused in the given way.
+    ///
+    /// The rights of each result are not returned unless requested with
+    /// [`GuardianRequestBuilder::show_rights`], which is useful to
+    /// double-check them before redistributing content.
+    ///
+    /// # Async example
+    /// ```ignore
+    /// let response = client
+    ///         .build_request()
+    ///         .search("Elections")
+    ///         .rights(RightsFilter::Syndicatable)
+    ///         .show_rights(true)
+    ///         .send()
+    ///         .await?;
+    ///
+    /// let syndicatable = response
+    ///         .results
+    ///         .unwrap_or_default()
+    ///         .into_iter()
+    ///         .filter(|result| {
+    ///             result
+    ///                 .rights
+    ///                 .as_ref()
+    ///                 .is_some_and(|rights| rights.allows(RightsFilter::Syndicatable))
+    ///         });
+    /// ```
+    pub fn rights(mut self, rights: RightsFilter) -> GuardianRequestBuilder {
+        self.request
+            .insert(String::from("rights"), rights.to_string());
+        self
+    }
+
+    /// Set a query parameter to an expression over ids, or record the
+    /// expression to fail the request with [`Error::InvalidId`] when it
+    /// is sent if it is not valid.
+    fn expression<T>(mut self, name: &str, expression: Expression<T>) -> GuardianRequestBuilder {
+        match expression.into_param() {
+            Ok(value) => {
+                self.request.insert(String::from(name), value);
+            }
+            Err(id) => self.invalid_id = Some(id),
+        }
+        self
+    }
+
+    /// Set a query parameter which has no dedicated method on this builder,
+    /// such as `show-related`, used by the endpoint-specific builders.
+    pub(crate) fn param(mut self, name: &str, value: impl ToString) -> GuardianRequestBuilder {
+        self.request.insert(String::from(name), value.to_string());
+        self
+    }
+
     /// Terminal operation that sends a GET request to the Guardian API.
     /// Once this function is called, all the query parameters constructed
     /// via the building methods are dropped.
     #[cfg(not(feature = "blocking"))]
     pub async fn send(&mut self) -> Result<SearchResponse, Error> {
-        let mut headers = HeaderMap::new();
-        if !self.api_key.is_empty() {
-            headers.insert("api-key", HeaderValue::from_str(&self.api_key).unwrap());
-        }
+        let (url, headers) = self.prepare()?;
+        let response = self.fetch(url, headers).await?;
+        let search = crate::helpers::parse_response(&response.body, self.strict)?
+            .unwrap_or_else(crate::helpers::mock_response);
 
-        let endpoint = match self.endpoint {
-            Endpoint::Content => String::from("search"),
-            Endpoint::Tags => self.endpoint.to_string(),
-            Endpoint::Sections => self.endpoint.to_string(),
-            Endpoint::Editions => self.endpoint.to_string(),
-            Endpoint::SingleItem => self
-                .request
-                .get("q")
-                .ok_or(Error::MissingQueryParameter("q"))?
-                .to_owned(),
-        };
+        self.request.clear();
 
-        let queries = Vec::from_iter(self.request.iter());
+        Ok(search)
+    }
 
-        let mut url = self.base_url.clone();
-        url.path_segments_mut().unwrap().push(&endpoint);
-
-        let search = self
-            .http_client
-            .get(url)
-            .headers(headers)
-            .query(&queries)
-            .send()
-            .await?
-            .json::<Response>()
-            .await?;
+    /// Send the request like [`GuardianRequestBuilder::send`], returning
+    /// the body of the response as is instead of deserializing it, e.g. to
+    /// read members which are not modelled by this crate, or to forward the
+    /// response verbatim. Errors reported by the API are still returned as
+    /// [`Error::ApiError`].
+    ///
+    /// The body can be parsed into a [`serde_json::Value`] with
+    /// [`serde_json::from_str`] when needed.
+    ///
+    /// # Async example
+    /// ```ignore
+    /// let client = aletheia::GuardianContentClient::new("YOUR_API_KEY");
+    /// let raw = client
+    ///         .build_request()
+    ///         .search("Elections")
+    ///         .send_raw()
+    ///         .await?;
+    ///
+    /// println!("{}", raw.get());
+    /// ```
+    #[cfg(not(feature = "blocking"))]
+    pub async fn send_raw(&mut self) -> Result<Box<RawValue>, Error> {
+        let (url, headers) = self.prepare()?;
+        let response = self.fetch(url, headers).await?;
+        crate::helpers::check_response(&response.body)?;
+        let raw = serde_json::from_slice(&response.body)?;
 
-        if let Some(err) = search.message {
-            return Err(Error::ApiError(err));
-        }
+        self.request.clear();
 
-        if let Some(response_content) = &search.response {
-            if response_content.status.as_deref() == Some("error") {
-                if let Some(message) = &response_content.message {
-                    return Err(Error::ApiError(message.to_owned()));
-                }
-            }
-        }
+        Ok(raw)
+    }
+
+    /// Send the request like [`GuardianRequestBuilder::send`], returning
+    /// the bytes of the body of the response along with the deserialized
+    /// response.
+    #[cfg(not(feature = "blocking"))]
+    pub async fn send_with_raw(&mut self) -> Result<(SearchResponse, Vec<u8>), Error> {
+        let (url, headers) = self.prepare()?;
+        let response = self.fetch(url, headers).await?;
+        let search = crate::helpers::parse_response(&response.body, self.strict)?
+            .unwrap_or_else(crate::helpers::mock_response);
 
         self.request.clear();
 
-        match search.response {
-            Some(r) => Ok(r),
-            None => Ok(crate::helpers::mock_response()),
-        }
+        Ok((search, response.body))
+    }
+
+    /// Send the request like [`GuardianRequestBuilder::send`],
+    /// deserializing the response into an endpoint-specific type.
+    #[cfg(not(feature = "blocking"))]
+    pub(crate) async fn send_as<T: DeserializeOwned + Default + UnknownMembers>(
+        &mut self,
+    ) -> Result<T, Error> {
+        let (url, headers) = self.prepare()?;
+        let response = self.fetch(url, headers).await?;
+        let typed =
+            crate::helpers::parse_response(&response.body, self.strict)?.unwrap_or_default();
+
+        self.request.clear();
+
+        Ok(typed)
     }
 
     /// Terminal operation that sends a GET request to the Guardian API.
@@ -774,6 +971,84 @@ impl GuardianRequestBuilder {
     /// via the building methods are dropped.
     #[cfg(feature = "blocking")]
     pub fn send(&mut self) -> Result<SearchResponse, Error> {
+        let (url, headers) = self.prepare()?;
+        let response = self.fetch(url, headers)?;
+        let search = crate::helpers::parse_response(&response.body, self.strict)?
+            .unwrap_or_else(crate::helpers::mock_response);
+
+        self.request.clear();
+
+        Ok(search)
+    }
+
+    /// Send the request like [`GuardianRequestBuilder::send`], returning
+    /// the body of the response as is instead of deserializing it, e.g. to
+    /// read members which are not modelled by this crate, or to forward the
+    /// response verbatim. Errors reported by the API are still returned as
+    /// [`Error::ApiError`].
+    ///
+    /// The body can be parsed into a [`serde_json::Value`] with
+    /// [`serde_json::from_str`] when needed.
+    ///
+    /// # Blocking example
+    /// ```ignore
+    /// let client = aletheia::GuardianContentClient::new("YOUR_API_KEY");
+    /// let raw = client
+    ///         .build_request()
+    ///         .search("Elections")
+    ///         .send_raw()?;
+    ///
+    /// println!("{}", raw.get());
+    /// ```
+    #[cfg(feature = "blocking")]
+    pub fn send_raw(&mut self) -> Result<Box<RawValue>, Error> {
+        let (url, headers) = self.prepare()?;
+        let response = self.fetch(url, headers)?;
+        crate::helpers::check_response(&response.body)?;
+        let raw = serde_json::from_slice(&response.body)?;
+
+        self.request.clear();
+
+        Ok(raw)
+    }
+
+    /// Send the request like [`GuardianRequestBuilder::send`], returning
+    /// the bytes of the body of the response along with the deserialized
+    /// response.
+    #[cfg(feature = "blocking")]
+    pub fn send_with_raw(&mut self) -> Result<(SearchResponse, Vec<u8>), Error> {
+        let (url, headers) = self.prepare()?;
+        let response = self.fetch(url, headers)?;
+        let search = crate::helpers::parse_response(&response.body, self.strict)?
+            .unwrap_or_else(crate::helpers::mock_response);
+
+        self.request.clear();
+
+        Ok((search, response.body))
+    }
+
+    /// Send the request like [`GuardianRequestBuilder::send`],
+    /// deserializing the response into an endpoint-specific type.
+    #[cfg(feature = "blocking")]
+    pub(crate) fn send_as<T: DeserializeOwned + Default + UnknownMembers>(
+        &mut self,
+    ) -> Result<T, Error> {
+        let (url, headers) = self.prepare()?;
+        let response = self.fetch(url, headers)?;
+        let typed =
+            crate::helpers::parse_response(&response.body, self.strict)?.unwrap_or_default();
+
+        self.request.clear();
+
+        Ok(typed)
+    }
+
+    /// Build the URL, including all query parameters, and the headers of the request.
+    fn prepare(&self) -> Result<(Url, HeaderMap), Error> {
+        if let Some(id) = &self.invalid_id {
+            return Err(Error::InvalidId(id.clone()));
+        }
+
         let mut headers = HeaderMap::new();
         if !self.api_key.is_empty() {
             headers.insert("api-key", HeaderValue::from_str(&self.api_key).unwrap());
@@ -784,43 +1059,144 @@ impl GuardianRequestBuilder {
             Endpoint::Tags => self.endpoint.to_string(),
             Endpoint::Sections => self.endpoint.to_string(),
             Endpoint::Editions => self.endpoint.to_string(),
-            Endpoint::SingleItem => self
-                .request
-                .get("q")
-                .ok_or(Error::MissingQueryParameter("q"))?
-                .to_owned(),
+            Endpoint::SingleItem => match &self.path {
+                Some(path) => path.to_owned(),
+                None => self
+                    .request
+                    .get("q")
+                    .ok_or(Error::MissingQueryParameter("q"))?
+                    .to_owned(),
+            },
         };
 
-        let queries = Vec::from_iter(self.request.iter());
-
         let mut url = self.base_url.clone();
-        url.path_segments_mut().unwrap().push(&endpoint);
+        // Safety: base URLs that cannot be a base are rejected when building the client
+        url.path_segments_mut()
+            .unwrap()
+            .pop_if_empty()
+            .extend(endpoint.split('/').filter(|segment| !segment.is_empty()));
+
+        if !self.request.is_empty() {
+            url.query_pairs_mut().extend_pairs(self.request.iter());
+        }
 
-        let search = self
-            .http_client
-            .get(url)
-            .headers(headers)
-            .query(&queries)
-            .send()?
-            .json::<Response>()?;
+        Ok((url, headers))
+    }
 
-        if let Some(err) = search.message {
-            return Err(Error::ApiError(err));
-        }
+    /// Serve a request from the cache of the client if possible,
+    /// otherwise send it and cache the response.
+    #[cfg(not(feature = "blocking"))]
+    async fn fetch(&self, url: Url, mut headers: HeaderMap) -> Result<HttpResponse, Error> {
+        let Some(cache) = &self.cache else {
+            return self.execute(url, headers).await;
+        };
+
+        // Stores such as `DiskCache` read and write files, so they are
+        // accessed on the blocking thread pool
+        let key = crate::helpers::normalized_url(&url);
+        let (lookup, headers) = {
+            let (cache, key, endpoint) = (cache.clone(), key.clone(), self.endpoint.clone());
+            tokio::task::spawn_blocking(move || {
+                let lookup = cache.lookup(&key, &endpoint, &mut headers);
+                (lookup, headers)
+            })
+            .await?
+        };
+        let stale = match lookup {
+            Lookup::Hit(entry) => return Ok(HttpResponse::json(entry.body)),
+            Lookup::Miss(stale) => stale,
+        };
+
+        let response = self.execute(url, headers).await?;
+        let cache = cache.clone();
+        Ok(tokio::task::spawn_blocking(move || cache.update(&key, stale, response)).await?)
+    }
 
-        if let Some(response_content) = &search.response {
-            if response_content.status.as_deref() == Some("error") {
-                if let Some(message) = &response_content.message {
-                    return Err(Error::ApiError(message.to_owned()));
+    /// Serve a request from the cache of the client if possible,
+    /// otherwise send it and cache the response.
+    #[cfg(feature = "blocking")]
+    fn fetch(&self, url: Url, mut headers: HeaderMap) -> Result<HttpResponse, Error> {
+        let Some(cache) = &self.cache else {
+            return self.execute(url, headers);
+        };
+
+        let key = crate::helpers::normalized_url(&url);
+        let stale = match cache.lookup(&key, &self.endpoint, &mut headers) {
+            Lookup::Hit(entry) => return Ok(HttpResponse::json(entry.body)),
+            Lookup::Miss(stale) => stale,
+        };
+
+        let response = self.execute(url, headers)?;
+        Ok(cache.update(&key, stale, response))
+    }
+
+    /// Send a request through the transport of the client, applying
+    /// its rate limit, quota and retry policy.
+    #[cfg(not(feature = "blocking"))]
+    async fn execute(&self, url: Url, headers: HeaderMap) -> Result<HttpResponse, Error> {
+        let started = Instant::now();
+        let mut attempt = 1;
+
+        loop {
+            if let Some(rate_limiter) = &self.rate_limiter {
+                rate_limiter.acquire().await?;
+            }
+            if let Some(quota) = &self.quota {
+                quota.acquire().await?;
+            }
+
+            let result = self.transport.get(url.clone(), headers.clone()).await;
+
+            let outcome = match &result {
+                Ok(response) => Outcome::Response(response.status, &response.headers),
+                Err(err) => Outcome::Error(err),
+            };
+
+            match self
+                .retry_policy
+                .retry_delay(attempt, started.elapsed(), outcome)
+            {
+                Some(delay) => {
+                    tokio::time::sleep(delay).await;
+                    attempt += 1;
                 }
+                None => return result,
             }
         }
+    }
 
-        self.request.clear();
+    /// Send a request through the transport of the client, applying
+    /// its rate limit, quota and retry policy.
+    #[cfg(feature = "blocking")]
+    fn execute(&self, url: Url, headers: HeaderMap) -> Result<HttpResponse, Error> {
+        let started = Instant::now();
+        let mut attempt = 1;
 
-        match search.response {
-            Some(r) => Ok(r),
-            None => Ok(crate::helpers::mock_response()),
+        loop {
+            if let Some(rate_limiter) = &self.rate_limiter {
+                rate_limiter.acquire()?;
+            }
+            if let Some(quota) = &self.quota {
+                quota.acquire()?;
+            }
+
+            let result = self.transport.get(url.clone(), headers.clone());
+
+            let outcome = match &result {
+                Ok(response) => Outcome::Response(response.status, &response.headers),
+                Err(err) => Outcome::Error(err),
+            };
+
+            match self
+                .retry_policy
+                .retry_delay(attempt, started.elapsed(), outcome)
+            {
+                Some(delay) => {
+                    std::thread::sleep(delay);
+                    attempt += 1;
+                }
+                None => return result,
+            }
         }
     }
 }
@@ -841,10 +1217,15 @@ impl GuardianContentClient {
     /// ```
     pub fn new(api_key: &str) -> GuardianContentClient {
         Self {
-            http_client: ReqwestClient::new(),
+            transport: Arc::new(ReqwestTransport::default()),
             // Safety: it's ok to unwrap here since we are passing a valid URL string
             base_url: Url::parse(GUARDIAN_CONTENT_API_URL).unwrap(),
             api_key: String::from(api_key),
+            retry_policy: RetryPolicy::none(),
+            rate_limiter: None,
+            quota: None,
+            cache: None,
+            strict: false,
         }
     }
 
@@ -875,19 +1256,398 @@ impl GuardianContentClient {
     /// ```
     pub fn build_request(&self) -> GuardianRequestBuilder {
         GuardianRequestBuilder {
-            http_client: self.http_client.clone(),
+            transport: Arc::clone(&self.transport),
             base_url: self.base_url.clone(),
             api_key: self.api_key.clone(),
             request: HashMap::new(),
             endpoint: Endpoint::default(),
+            path: None,
+            retry_policy: self.retry_policy.clone(),
+            rate_limiter: self.rate_limiter.clone(),
+            quota: self.quota.clone(),
+            cache: self.cache.clone(),
+            strict: self.strict,
+            invalid_id: None,
+        }
+    }
+
+    /// Start building a request to the [`Endpoint::Tags`] endpoint,
+    /// whose response is deserialized into a [`TagsResponse`].
+    ///
+    /// # Async example
+    /// ```ignore
+    /// let response = client
+    ///         .tags()
+    ///         .search("food")
+    ///         .send()
+    ///         .await?;
+    /// ```
+    pub fn tags(&self) -> TagsRequestBuilder {
+        TagsRequestBuilder::new(self.build_request().endpoint(Endpoint::Tags))
+    }
+
+    /// Start building a request to the [`Endpoint::Sections`] endpoint,
+    /// whose response is deserialized into a [`SectionsResponse`].
+    pub fn sections(&self) -> SectionsRequestBuilder {
+        SectionsRequestBuilder::new(self.build_request().endpoint(Endpoint::Sections))
+    }
+
+    /// Start building a request to the [`Endpoint::Editions`] endpoint,
+    /// whose response is deserialized into an [`EditionsResponse`].
+    pub fn editions(&self) -> EditionsRequestBuilder {
+        EditionsRequestBuilder::new(self.build_request().endpoint(Endpoint::Editions))
+    }
+
+    /// Start building a request for a single item, i.e. a piece of content,
+    /// a tag, a section or an edition, whose response is deserialized into
+    /// an [`ItemResponse`]. The id of an item is its path on theguardian.com.
+    ///
+    /// Takes any of the ids of the [`ids`] module, or a `&str`. If the id
+    /// is not valid, sending the request returns [`Error::InvalidId`].
+    /// See [`ItemId`].
+    ///
+    /// # Async example
+    /// ```ignore
+    /// let response = client
+    ///         .item("books/2022/jan/01/2022-in-books-highlights-for-the-year-ahead")
+    ///         .show_fields(vec![Field::Body])
+    ///         .send()
+    ///         .await?;
+    ///
+    /// println!("{:#?}", response.content);
+    /// ```
+    pub fn item(&self, id: impl Into<ItemId>) -> ItemRequestBuilder {
+        let mut request = self.build_request().endpoint(Endpoint::SingleItem);
+        match id.into().into_path() {
+            Ok(path) => request.path = Some(path),
+            Err(id) => request.invalid_id = Some(id),
+        }
+        ItemRequestBuilder::new(request)
+    }
+
+    /// Start building a request to the [`Endpoint::SingleItem`] endpoint for
+    /// the item at `url`, which can be a `web_url`, e.g. a link to
+    /// <https://www.theguardian.com>, an `api_url` or a bare path.
+    ///
+    /// Returns [`Error::InvalidId`] if `url` is not the URL of an item
+    /// on one of the Guardian's hosts.
+    /// See [`ContentId::from_url`] for details.
+    ///
+    /// # Async example
+    /// ```ignore
+    /// let response = client
+    ///         .item_from_url("https://www.theguardian.com/books/2022/jan/01/2022-in-books?CMP=share_btn_url")?
+    ///         .send()
+    ///         .await?;
+    /// ```
+    pub fn item_from_url(&self, url: &str) -> Result<ItemRequestBuilder, Error> {
+        Ok(self.item(ItemId::from_url(url)?))
+    }
+
+    /// Start building a request for a single content atom, whose response
+    /// is deserialized into an [`AtomResponse`].
+    ///
+    /// # Async example
+    /// ```ignore
+    /// let response = client
+    ///         .atom(AtomLookupType::Explainer, "8a7b1f5e-0a8c-4a3b-9b7e-2f1c0d9e6a51")
+    ///         .send()
+    ///         .await?;
+    ///
+    /// if let Some(AtomData::Explainer(explainer)) = response.atom.map(|atom| atom.data) {
+    ///     println!("{}", explainer.body);
+    /// }
+    /// ```
+    pub fn atom(&self, atom_type: AtomLookupType, id: &str) -> AtomRequestBuilder {
+        let mut request = self.build_request().endpoint(Endpoint::SingleItem);
+        request.path = Some(format!("atom/{atom_type}/{id}"));
+        AtomRequestBuilder::new(request)
+    }
+
+    /// The [`QuotaTracker`] counting the requests made by this client,
+    /// if one was set with [`GuardianContentClientBuilder::quota`].
+    pub fn quota(&self) -> Option<&QuotaTracker> {
+        self.quota.as_ref()
+    }
+
+    /// Start configuring a client with settings other than the defaults
+    /// used by [`GuardianContentClient::new`], such as a different base URL,
+    /// timeouts or a proxy.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let client = aletheia::GuardianContentClient::builder("YOUR_API_KEY")
+    ///         .base_url("http://localhost:8080")
+    ///         .timeout(std::time::Duration::from_secs(10))
+    ///         .user_agent("my-app/1.0")
+    ///         .build()?;
+    /// ```
+    pub fn builder(api_key: &str) -> GuardianContentClientBuilder {
+        GuardianContentClientBuilder {
+            api_key: String::from(api_key),
+            base_url: String::from(GUARDIAN_CONTENT_API_URL),
+            timeout: None,
+            connect_timeout: None,
+            #[cfg(not(feature = "blocking"))]
+            read_timeout: None,
+            user_agent: None,
+            default_headers: HeaderMap::new(),
+            proxies: Vec::new(),
+            transport: None,
+            retry_policy: RetryPolicy::none(),
+            rate_limit: None,
+            quota: None,
+            cache: None,
+            strict: false,
         }
     }
 }
 
+/// A builder to configure a [`GuardianContentClient`].
+///
+/// It is created by calling [`GuardianContentClient::builder`].
+/// Settings which are not explicitly set fall back to the same
+/// defaults used by [`GuardianContentClient::new`].
+#[derive(Debug)]
+pub struct GuardianContentClientBuilder {
+    api_key: String,
+    base_url: String,
+    timeout: Option<Duration>,
+    connect_timeout: Option<Duration>,
+    #[cfg(not(feature = "blocking"))]
+    read_timeout: Option<Duration>,
+    user_agent: Option<String>,
+    default_headers: HeaderMap,
+    proxies: Vec<Proxy>,
+    transport: Option<Arc<dyn Transport>>,
+    retry_policy: RetryPolicy,
+    rate_limit: Option<RateLimit>,
+    quota: Option<QuotaTracker>,
+    cache: Option<ResponseCache>,
+    strict: bool,
+}
+
+impl GuardianContentClientBuilder {
+    /// Send requests to a different base URL, e.g. a local stand-in
+    /// or a staging proxy, instead of <https://content.guardianapis.com>.
+    pub fn base_url(mut self, base_url: &str) -> GuardianContentClientBuilder {
+        self.base_url = String::from(base_url);
+        self
+    }
+
+    /// Set a timeout for the whole request, from connecting
+    /// until the response body has been read.
+    pub fn timeout(mut self, timeout: Duration) -> GuardianContentClientBuilder {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set a timeout for the connect phase of a request.
+    pub fn connect_timeout(mut self, timeout: Duration) -> GuardianContentClientBuilder {
+        self.connect_timeout = Some(timeout);
+        self
+    }
+
+    /// Set a timeout for each read operation on the response.
+    ///
+    /// Only available on the asynchronous client.
+    #[cfg(not(feature = "blocking"))]
+    pub fn read_timeout(mut self, timeout: Duration) -> GuardianContentClientBuilder {
+        self.read_timeout = Some(timeout);
+        self
+    }
+
+    /// Set the `User-Agent` header sent with every request.
+    pub fn user_agent(mut self, user_agent: &str) -> GuardianContentClientBuilder {
+        self.user_agent = Some(String::from(user_agent));
+        self
+    }
+
+    /// Set headers sent with every request, in addition to the API key.
+    pub fn default_headers(mut self, headers: HeaderMap) -> GuardianContentClientBuilder {
+        self.default_headers = headers;
+        self
+    }
+
+    /// Route requests through a proxy.
+    /// Can be called multiple times to add more than one proxy.
+    pub fn proxy(mut self, proxy: Proxy) -> GuardianContentClientBuilder {
+        self.proxies.push(proxy);
+        self
+    }
+
+    /// Use a preconfigured HTTP client instead of constructing one.
+    ///
+    /// When a client is provided, the timeouts, user agent, default headers
+    /// and proxies set on this builder are ignored, as they are properties
+    /// of the HTTP client itself.
+    pub fn http_client(mut self, http_client: ReqwestClient) -> GuardianContentClientBuilder {
+        self.transport = Some(Arc::new(ReqwestTransport::new(http_client)));
+        self
+    }
+
+    /// Send requests through a custom [`Transport`] instead of `reqwest`,
+    /// e.g. an [`InMemoryTransport`](transport::InMemoryTransport) serving
+    /// canned responses in tests.
+    ///
+    /// As with [`GuardianContentClientBuilder::http_client`], the timeouts,
+    /// user agent, default headers and proxies set on this builder are ignored.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let transport = InMemoryTransport::new()
+    ///         .route("search", HttpResponse::json(include_str!("search.json")));
+    ///
+    /// let client = aletheia::GuardianContentClient::builder("test-api-key")
+    ///         .transport(transport)
+    ///         .build()?;
+    /// ```
+    pub fn transport(
+        mut self,
+        transport: impl Transport + 'static,
+    ) -> GuardianContentClientBuilder {
+        self.transport = Some(Arc::new(transport));
+        self
+    }
+
+    /// Retry requests that fail with a transient error according to the
+    /// given [`RetryPolicy`]. By default, requests are not retried.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let client = aletheia::GuardianContentClient::builder("YOUR_API_KEY")
+    ///         .retry_policy(RetryPolicy::default().max_attempts(5))
+    ///         .build()?;
+    /// ```
+    pub fn retry_policy(mut self, retry_policy: RetryPolicy) -> GuardianContentClientBuilder {
+        self.retry_policy = retry_policy;
+        self
+    }
+
+    /// Limit how fast requests are sent, according to the given [`RateLimit`].
+    /// The limit is shared by all clones of the client.
+    /// By default, requests are not rate limited.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let client = aletheia::GuardianContentClient::builder("YOUR_API_KEY")
+    ///         .rate_limit(RateLimit::developer())
+    ///         .build()?;
+    /// ```
+    pub fn rate_limit(mut self, rate_limit: RateLimit) -> GuardianContentClientBuilder {
+        self.rate_limit = Some(rate_limit);
+        self
+    }
+
+    /// Track the number of requests made each day with the given
+    /// [`QuotaTracker`]. Once the daily limit is reached, requests fail
+    /// with [`Error::QuotaExhausted`] without calling the API.
+    /// By default, requests are not tracked.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let client = aletheia::GuardianContentClient::builder("YOUR_API_KEY")
+    ///         .quota(QuotaTracker::developer().persist_to("quota.json"))
+    ///         .build()?;
+    /// ```
+    pub fn quota(mut self, quota: QuotaTracker) -> GuardianContentClientBuilder {
+        self.quota = Some(quota);
+        self
+    }
+
+    /// Cache responses according to the given [`ResponseCache`], so that
+    /// identical requests are served without calling the API again.
+    /// The cache is shared by all clones of the client.
+    /// By default, responses are not cached.
+    ///
+    /// # Example
+    /// ```ignore
+    /// let client = aletheia::GuardianContentClient::builder("YOUR_API_KEY")
+    ///         .cache(ResponseCache::in_memory())
+    ///         .build()?;
+    /// ```
+    pub fn cache(mut self, cache: ResponseCache) -> GuardianContentClientBuilder {
+        self.cache = Some(cache);
+        self
+    }
+
+    /// Fail deserialization with [`Error::JsonError`] when a response has
+    /// members which are not modelled by this crate, instead of collecting
+    /// them in the `extra` map of the struct they belong to. This applies to
+    /// [`SearchResult`], [`Content`], [`Tag`], [`Section`], [`structs::Block`],
+    /// [`BlockElement`] and [`Fields`]; unknown members of other structs are
+    /// ignored. Responses are deserialized as [`strict::Strict`] values.
+    ///
+    /// This is useful to detect changes in the API, e.g. in a scheduled
+    /// job, but should not be enabled otherwise: the API adds new members
+    /// without notice. Strict mode is disabled by default.
+    pub fn strict(mut self, strict: bool) -> GuardianContentClientBuilder {
+        self.strict = strict;
+        self
+    }
+
+    /// Build the [`GuardianContentClient`].
+    ///
+    /// Returns an error if the base URL is invalid, or if the
+    /// underlying HTTP client could not be constructed.
+    pub fn build(self) -> Result<GuardianContentClient, Error> {
+        let base_url = Url::parse(&self.base_url)
+            .map_err(|err| Error::InvalidBaseUrl(format!("{}: {err}", self.base_url)))?;
+
+        if base_url.cannot_be_a_base() {
+            return Err(Error::InvalidBaseUrl(self.base_url));
+        }
+
+        let transport: Arc<dyn Transport> = match self.transport {
+            Some(transport) => transport,
+            None => {
+                let mut builder = ReqwestClient::builder().default_headers(self.default_headers);
+
+                if let Some(timeout) = self.timeout {
+                    builder = builder.timeout(timeout);
+                }
+                if let Some(timeout) = self.connect_timeout {
+                    builder = builder.connect_timeout(timeout);
+                }
+                #[cfg(not(feature = "blocking"))]
+                if let Some(timeout) = self.read_timeout {
+                    builder = builder.read_timeout(timeout);
+                }
+                if let Some(user_agent) = self.user_agent {
+                    builder = builder.user_agent(user_agent);
+                }
+                for proxy in self.proxies {
+                    builder = builder.proxy(proxy);
+                }
+
+                Arc::new(ReqwestTransport::new(builder.build()?))
+            }
+        };
+
+        Ok(GuardianContentClient {
+            transport,
+            base_url,
+            api_key: self.api_key,
+            retry_policy: self.retry_policy,
+            rate_limiter: self.rate_limit.map(RateLimiter::new),
+            quota: self.quota,
+            cache: self.cache,
+            strict: self.strict,
+        })
+    }
+}
+
 mod helpers {
     use crate::enums::{Block, IsAll};
-    use crate::SearchResponse;
+    use crate::error::Error;
+    use crate::strict::{Strict, UnknownMembers};
+    use crate::structs::SearchResponse;
     use chrono::{FixedOffset, LocalResult, TimeZone};
+    use reqwest::Url;
+    use serde::de::DeserializeOwned;
+    use serde::Deserialize;
+    use std::borrow::Cow;
+    use std::collections::HashMap;
     use std::fmt::Display;
 
     pub(crate) fn generate_sequence<T>(items: Vec<T>) -> String
@@ -967,6 +1727,123 @@ mod helpers {
         }
     }
 
+    /// The page a paginated request starts from, i.e. the page number
+    /// set on the request, or the first page.
+    pub(crate) fn starting_page(request: &HashMap<String, String>) -> u32 {
+        request
+            .get("page")
+            .and_then(|page| page.parse().ok())
+            .unwrap_or(1)
+    }
+
+    /// The page following the one in `response`, if there is one.
+    pub(crate) fn next_page(response: &SearchResponse) -> Option<u32> {
+        if response.results.as_ref().is_none_or(Vec::is_empty) {
+            return None;
+        }
+
+        let current_page = response.current_page?;
+        let pages = response.pages?;
+
+        if (current_page as isize) < pages {
+            Some(current_page + 1)
+        } else {
+            None
+        }
+    }
+
+    /// The URL of a request in a canonical form: query parameters are sorted,
+    /// and the value of any `api-key` parameter is redacted.
+    pub(crate) fn normalized_url(url: &Url) -> String {
+        let mut query = url
+            .query_pairs()
+            .map(|(key, value)| {
+                let value = if key == "api-key" {
+                    Cow::Borrowed("REDACTED")
+                } else {
+                    value
+                };
+                (key, value)
+            })
+            .collect::<Vec<_>>();
+        query.sort();
+
+        let mut normalized = url.clone();
+        normalized.set_fragment(None);
+        normalized.set_query(None);
+        if !query.is_empty() {
+            normalized.query_pairs_mut().extend_pairs(query);
+        }
+
+        normalized.to_string()
+    }
+
+    /// A stable 64-bit FNV-1a hash of `value`, as a hexadecimal string.
+    pub(crate) fn fingerprint(value: &str) -> String {
+        let hash = value.bytes().fold(0xcbf29ce484222325_u64, |hash, byte| {
+            (hash ^ u64::from(byte)).wrapping_mul(0x100000001b3)
+        });
+        format!("{hash:016x}")
+    }
+
+    /// The envelope wrapping every response of the API.
+    #[derive(Deserialize)]
+    struct Envelope<T> {
+        message: Option<String>,
+        response: Option<T>,
+    }
+
+    impl<T: UnknownMembers> UnknownMembers for Envelope<T> {
+        fn unknown_member(&self) -> Option<&str> {
+            self.response.unknown_member()
+        }
+    }
+
+    /// The status of a response, used to detect errors
+    /// before deserializing the rest of it.
+    #[derive(Deserialize)]
+    struct Status {
+        status: Option<String>,
+        message: Option<String>,
+    }
+
+    /// Check the body of a response for errors reported by the API,
+    /// turning them into [`Error::ApiError`].
+    pub(crate) fn check_response(body: &[u8]) -> Result<(), Error> {
+        let envelope: Envelope<Status> = serde_json::from_slice(body)?;
+
+        if let Some(err) = envelope.message {
+            return Err(Error::ApiError(err));
+        }
+
+        if let Some(status) = envelope.response {
+            if status.status.as_deref() == Some("error") {
+                if let Some(message) = status.message {
+                    return Err(Error::ApiError(message));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Deserialize the body of a response, turning errors
+    /// reported by the API into [`Error::ApiError`].
+    /// In strict mode, members not modelled by the response structs are errors.
+    pub(crate) fn parse_response<T: DeserializeOwned + UnknownMembers>(
+        body: &[u8],
+        strict: bool,
+    ) -> Result<Option<T>, Error> {
+        check_response(body)?;
+
+        let envelope: Envelope<T> = if strict {
+            serde_json::from_slice::<Strict<Envelope<T>>>(body)?.into_inner()
+        } else {
+            serde_json::from_slice(body)?
+        };
+        Ok(envelope.response)
+    }
+
     pub(crate) fn mock_response() -> SearchResponse {
         SearchResponse {
             status: None,
@@ -0,0 +1,164 @@
+//! Automatic pagination over the results of a request.
+//!
+//! Requests sent with [`GuardianRequestBuilder::send`] only ever fetch
+//! a single page of results. The methods in this module keep a copy of
+//! the original query parameters and issue one request per page,
+//! following `current_page` and `pages` in each [`SearchResponse`].
+
+use crate::error::Error;
+use crate::structs::SearchResponse;
+use crate::structs::SearchResult;
+use crate::GuardianRequestBuilder;
+#[cfg(not(feature = "blocking"))]
+use futures_util::{stream, Stream, TryStreamExt};
+
+impl GuardianRequestBuilder {
+    /// Turn the request into a stream of pages.
+    ///
+    /// The stream starts from the page set with [`GuardianRequestBuilder::page`]
+    /// (or the first page if none was set), and keeps requesting the next page
+    /// until the API reports there are no more pages or `max_pages` pages have
+    /// been fetched. All other query parameters are sent with every request.
+    ///
+    /// The stream ends after yielding the first error.
+    ///
+    /// # Async example
+    /// ```ignore
+    /// use futures_util::TryStreamExt;
+    ///
+    /// let mut pages = client
+    ///         .build_request()
+    ///         .search("Elections")
+    ///         .page_size(50)
+    ///         .stream_pages(Some(5));
+    ///
+    /// while let Some(page) = pages.try_next().await? {
+    ///     println!("Page {:?} of {:?}", page.current_page, page.pages);
+    /// }
+    /// ```
+    #[cfg(not(feature = "blocking"))]
+    pub fn stream_pages(
+        self,
+        max_pages: Option<u32>,
+    ) -> impl Stream<Item = Result<SearchResponse, Error>> {
+        let first_page = crate::helpers::starting_page(&self.request);
+
+        stream::try_unfold(
+            (self, Some(first_page), 0),
+            move |(template, next_page, fetched)| async move {
+                let Some(page) = next_page else {
+                    return Ok(None);
+                };
+                if max_pages.is_some_and(|max| fetched >= max) {
+                    return Ok(None);
+                }
+
+                let response = template.clone().page(page).send().await?;
+                let next_page = crate::helpers::next_page(&response);
+
+                Ok(Some((response, (template, next_page, fetched + 1))))
+            },
+        )
+    }
+
+    /// Turn the request into a stream of individual results.
+    ///
+    /// This behaves like [`GuardianRequestBuilder::stream_pages`], but
+    /// flattens the results of every page into a single stream.
+    ///
+    /// # Async example
+    /// ```ignore
+    /// use futures_util::TryStreamExt;
+    ///
+    /// let results: Vec<SearchResult> = client
+    ///         .build_request()
+    ///         .search("Elections")
+    ///         .stream_results(Some(3))
+    ///         .try_collect()
+    ///         .await?;
+    /// ```
+    #[cfg(not(feature = "blocking"))]
+    pub fn stream_results(
+        self,
+        max_pages: Option<u32>,
+    ) -> impl Stream<Item = Result<SearchResult, Error>> {
+        self.stream_pages(max_pages)
+            .map_ok(|response| {
+                stream::iter(response.results.unwrap_or_default().into_iter().map(Ok))
+            })
+            .try_flatten()
+    }
+
+    /// Turn the request into an iterator over pages.
+    ///
+    /// The iterator starts from the page set with [`GuardianRequestBuilder::page`]
+    /// (or the first page if none was set), and lazily requests the next page
+    /// until the API reports there are no more pages or `max_pages` pages have
+    /// been fetched. All other query parameters are sent with every request.
+    ///
+    /// The iterator ends after yielding the first error.
+    ///
+    /// # Blocking example
+    /// ```ignore
+    /// let pages = client
+    ///         .build_request()
+    ///         .search("Elections")
+    ///         .page_size(50)
+    ///         .iter_pages(Some(5));
+    ///
+    /// for page in pages {
+    ///     let page = page?;
+    ///     println!("Page {:?} of {:?}", page.current_page, page.pages);
+    /// }
+    /// ```
+    #[cfg(feature = "blocking")]
+    pub fn iter_pages(
+        self,
+        max_pages: Option<u32>,
+    ) -> impl Iterator<Item = Result<SearchResponse, Error>> {
+        let mut next_page = Some(crate::helpers::starting_page(&self.request));
+        let mut fetched = 0;
+
+        std::iter::from_fn(move || {
+            let page = next_page.take()?;
+            if max_pages.is_some_and(|max| fetched >= max) {
+                return None;
+            }
+
+            fetched += 1;
+            let response = self.clone().page(page).send();
+            if let Ok(response) = &response {
+                next_page = crate::helpers::next_page(response);
+            }
+
+            Some(response)
+        })
+    }
+
+    /// Turn the request into an iterator over individual results.
+    ///
+    /// This behaves like [`GuardianRequestBuilder::iter_pages`], but
+    /// flattens the results of every page into a single iterator.
+    ///
+    /// # Blocking example
+    /// ```ignore
+    /// let results = client
+    ///         .build_request()
+    ///         .search("Elections")
+    ///         .iter_results(Some(3))
+    ///         .collect::<Result<Vec<_>, _>>()?;
+    /// ```
+    #[cfg(feature = "blocking")]
+    pub fn iter_results(
+        self,
+        max_pages: Option<u32>,
+    ) -> impl Iterator<Item = Result<SearchResult, Error>> {
+        self.iter_pages(max_pages).flat_map(|page| {
+            let (results, error) = match page {
+                Ok(response) => (response.results.unwrap_or_default(), None),
+                Err(err) => (Vec::new(), Some(Err(err))),
+            };
+            results.into_iter().map(Ok).chain(error)
+        })
+    }
+}
@@ -0,0 +1,217 @@
+//! Daily quota tracking.
+//!
+//! On top of the per-second rate limit, API keys can only make a limited
+//! number of calls per day. A [`QuotaTracker`] set on the client with
+//! [`GuardianContentClientBuilder::quota`](crate::GuardianContentClientBuilder::quota)
+//! counts the requests made each UTC day, and makes requests fail with
+//! [`Error::QuotaExhausted`] once the daily allowance has been used up,
+//! without calling the API.
+//!
+//! The counter can be persisted to a file, so that multiple runs of a
+//! program, or multiple programs, share the same daily allowance. Updates
+//! of the file are serialized with an exclusive lock on a companion
+//! `.lock` file, so that concurrent requests are all counted.
+
+use crate::error::Error;
+use chrono::{NaiveDate, Utc};
+use fs4::fs_std::FileExt;
+use serde::{Deserialize, Serialize};
+use std::fs::{self, File, OpenOptions};
+use std::io::ErrorKind;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, PoisonError};
+
+/// Counts the requests made each UTC day against a daily limit.
+/// Clones share the same counter.
+///
+/// # Example
+/// ```ignore
+/// let quota = QuotaTracker::developer().persist_to("/var/lib/my-app/guardian-quota.json");
+///
+/// let client = GuardianContentClient::builder("YOUR_API_KEY")
+///         .quota(quota.clone())
+///         .build()?;
+///
+/// println!("{} requests left today", quota.remaining()?);
+/// ```
+#[derive(Clone, Debug)]
+pub struct QuotaTracker {
+    daily_limit: u32,
+    path: Option<PathBuf>,
+    usage: Arc<Mutex<Usage>>,
+}
+
+/// The number of requests made on a given day.
+#[derive(Clone, Copy, Debug, Deserialize, Serialize, Eq, PartialEq)]
+struct Usage {
+    date: NaiveDate,
+    count: u32,
+}
+
+impl Usage {
+    fn today() -> Self {
+        Self {
+            date: Utc::now().date_naive(),
+            count: 0,
+        }
+    }
+}
+
+impl QuotaTracker {
+    /// Allow up to `daily_limit` requests per UTC day.
+    pub fn new(daily_limit: u32) -> Self {
+        Self {
+            daily_limit,
+            path: None,
+            usage: Arc::new(Mutex::new(Usage::today())),
+        }
+    }
+
+    /// The daily limit for developer keys: 500 calls per day.
+    pub fn developer() -> Self {
+        Self::new(500)
+    }
+
+    /// Persist the counter to a file.
+    ///
+    /// The file is read before and written after every request, so that
+    /// all trackers persisting to the same file share one allowance.
+    /// It is created when the first request is made, if it doesn't exist,
+    /// along with a `.lock` file next to it which must not be removed
+    /// while the counter is in use.
+    pub fn persist_to(mut self, path: impl Into<PathBuf>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// The maximum number of requests per UTC day.
+    pub fn daily_limit(&self) -> u32 {
+        self.daily_limit
+    }
+
+    /// The number of requests made today.
+    ///
+    /// Returns an error if the counter is persisted and the file cannot be read.
+    pub fn used(&self) -> Result<u32, Error> {
+        let mut usage = self.usage.lock().unwrap_or_else(PoisonError::into_inner);
+        self.refresh(&mut usage)?;
+        Ok(usage.count)
+    }
+
+    /// The number of requests that can still be made today.
+    ///
+    /// Returns an error if the counter is persisted and the file cannot be read.
+    pub fn remaining(&self) -> Result<u32, Error> {
+        Ok(self.daily_limit.saturating_sub(self.used()?))
+    }
+
+    /// Count a request against today's allowance, or fail if
+    /// there is nothing left of it.
+    ///
+    /// Waits for the lock on the persisted counter, if any, on a thread
+    /// of the blocking pool, so that other tasks keep running while another
+    /// process holds it.
+    #[cfg(not(feature = "blocking"))]
+    pub(crate) async fn acquire(&self) -> Result<(), Error> {
+        if self.path.is_none() {
+            return self.try_acquire();
+        }
+
+        let quota = self.clone();
+        tokio::task::spawn_blocking(move || quota.try_acquire()).await?
+    }
+
+    /// Count a request against today's allowance, or fail if
+    /// there is nothing left of it.
+    #[cfg(feature = "blocking")]
+    pub(crate) fn acquire(&self) -> Result<(), Error> {
+        self.try_acquire()
+    }
+
+    /// Count a request against today's allowance, or fail if
+    /// there is nothing left of it, blocking the current thread
+    /// while the persisted counter is locked and updated.
+    pub(crate) fn try_acquire(&self) -> Result<(), Error> {
+        let mut usage = self.usage.lock().unwrap_or_else(PoisonError::into_inner);
+
+        // Held until the updated counter is stored, so that no other
+        // process can read the counter in the meantime
+        let _lock = self.lock_file()?;
+        self.refresh(&mut usage)?;
+
+        if usage.count >= self.daily_limit {
+            return Err(Error::QuotaExhausted(self.daily_limit));
+        }
+
+        let updated = Usage {
+            count: usage.count + 1,
+            ..*usage
+        };
+        self.store(&updated)?;
+        *usage = updated;
+
+        Ok(())
+    }
+
+    /// Take an exclusive lock on the lock file of the persisted counter,
+    /// which is released when the returned file is dropped.
+    fn lock_file(&self) -> Result<Option<File>, Error> {
+        let Some(path) = &self.path else {
+            return Ok(None);
+        };
+
+        if let Some(dir) = path.parent().filter(|dir| !dir.as_os_str().is_empty()) {
+            fs::create_dir_all(dir)?;
+        }
+
+        let mut lock_path = path.clone().into_os_string();
+        lock_path.push(".lock");
+        let file = OpenOptions::new()
+            .create(true)
+            .truncate(false)
+            .write(true)
+            .open(lock_path)?;
+        file.lock_exclusive()?;
+
+        Ok(Some(file))
+    }
+
+    /// Bring the in-memory counter up to date with the persisted one,
+    /// and reset it if the day has changed since it was last updated.
+    fn refresh(&self, usage: &mut Usage) -> Result<(), Error> {
+        if let Some(path) = &self.path {
+            match fs::read(path) {
+                Ok(contents) => {
+                    *usage = serde_json::from_slice(&contents)
+                        .map_err(|err| std::io::Error::new(ErrorKind::InvalidData, err))?;
+                }
+                Err(err) if err.kind() == ErrorKind::NotFound => {}
+                Err(err) => return Err(err.into()),
+            }
+        }
+
+        if usage.date != Utc::now().date_naive() {
+            *usage = Usage::today();
+        }
+
+        Ok(())
+    }
+
+    fn store(&self, usage: &Usage) -> Result<(), Error> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+
+        // Write to a temporary file first, so that other processes
+        // never read a partially written counter
+        let temporary_path = path.with_extension(format!("{:016x}.tmp", fastrand::u64(..)));
+        let contents = serde_json::to_vec(usage)
+            .map_err(|err| std::io::Error::new(ErrorKind::InvalidData, err))?;
+        fs::write(&temporary_path, contents)?;
+        fs::rename(&temporary_path, path).inspect_err(|_| {
+            let _ = fs::remove_file(&temporary_path);
+        })?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,162 @@
+//! Client-side rate limiting.
+//!
+//! API keys are limited in how many calls they can make per second.
+//! A [`RateLimit`] set on the client with
+//! [`GuardianContentClientBuilder::rate_limit`](crate::GuardianContentClientBuilder::rate_limit)
+//! makes sure requests are not sent faster than that. The limit is
+//! enforced with a token bucket shared by the client, all of its clones
+//! and all the requests built from them.
+
+use crate::error::Error;
+use std::sync::{Arc, Mutex, PoisonError};
+use std::time::{Duration, Instant};
+
+/// The number of calls allowed over a period of time.
+///
+/// # Example
+/// ```ignore
+/// // Use the limits of a developer key, and return an error
+/// // instead of waiting when they are exceeded
+/// let client = GuardianContentClient::builder("YOUR_API_KEY")
+///         .rate_limit(RateLimit::developer().fail_fast(true))
+///         .build()?;
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RateLimit {
+    calls: u32,
+    per: Duration,
+    burst: u32,
+    fail_fast: bool,
+}
+
+impl RateLimit {
+    /// Allow `calls` calls every `per`. A value of 0 for `calls` is treated as 1.
+    ///
+    /// By default, up to `calls` requests can be sent at once before
+    /// further requests are spread out evenly over `per`.
+    pub fn new(calls: u32, per: Duration) -> Self {
+        let calls = calls.max(1);
+        Self {
+            calls,
+            per,
+            burst: calls,
+            fail_fast: false,
+        }
+    }
+
+    /// The limit for developer keys: 1 call per second.
+    pub fn developer() -> Self {
+        Self::new(1, Duration::from_secs(1))
+    }
+
+    /// The preset limit for an API key tier, as reported in
+    /// [`SearchResponse::user_tier`](crate::structs::SearchResponse::user_tier).
+    ///
+    /// The API reports three tiers:
+    /// - `developer`: free keys, limited to 1 call per second.
+    /// - `rights-managed`: commercial keys, whose limits are set in each
+    ///   agreement with the Guardian, so there is no preset for them.
+    /// - `internal`: keys used by the Guardian itself, which are not limited.
+    ///
+    /// Returns `None` for tiers without a preset, including tiers
+    /// unknown to this crate.
+    pub fn for_tier(user_tier: &str) -> Option<Self> {
+        match user_tier {
+            "developer" => Some(Self::developer()),
+            _ => None,
+        }
+    }
+
+    /// Set how many requests can be sent at once when no requests
+    /// have been made for a while. A value of 0 is treated as 1.
+    pub fn burst(mut self, burst: u32) -> Self {
+        self.burst = burst.max(1);
+        self
+    }
+
+    /// When `true`, requests exceeding the limit fail immediately
+    /// with [`Error::RateLimitExceeded`] instead of waiting for
+    /// the limit to allow them.
+    pub fn fail_fast(mut self, fail_fast: bool) -> Self {
+        self.fail_fast = fail_fast;
+        self
+    }
+
+    /// The time it takes for a single call to become available again.
+    fn interval(&self) -> Duration {
+        self.per / self.calls
+    }
+}
+
+/// A token bucket enforcing a [`RateLimit`].
+/// Clones share the same bucket.
+#[derive(Clone, Debug)]
+pub(crate) struct RateLimiter {
+    limit: RateLimit,
+    bucket: Arc<Mutex<Bucket>>,
+}
+
+#[derive(Debug)]
+struct Bucket {
+    tokens: f64,
+    last_refill: Instant,
+}
+
+impl RateLimiter {
+    pub(crate) fn new(limit: RateLimit) -> Self {
+        let bucket = Bucket {
+            tokens: f64::from(limit.burst),
+            last_refill: Instant::now(),
+        };
+
+        Self {
+            limit,
+            bucket: Arc::new(Mutex::new(bucket)),
+        }
+    }
+
+    /// Take a token from the bucket if one is available, otherwise
+    /// return how long it will take for one to become available.
+    pub(crate) fn try_acquire(&self) -> Result<(), Duration> {
+        let mut bucket = self.bucket.lock().unwrap_or_else(PoisonError::into_inner);
+        let interval = self.limit.interval().as_secs_f64();
+
+        let now = Instant::now();
+        let refilled = now.duration_since(bucket.last_refill).as_secs_f64() / interval;
+        bucket.tokens = (bucket.tokens + refilled).min(f64::from(self.limit.burst));
+        bucket.last_refill = now;
+
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - bucket.tokens) * interval))
+        }
+    }
+
+    /// Wait until a request can be sent, or fail if the limit is
+    /// exceeded and the limit is set to fail fast.
+    #[cfg(not(feature = "blocking"))]
+    pub(crate) async fn acquire(&self) -> Result<(), Error> {
+        loop {
+            match self.try_acquire() {
+                Ok(()) => return Ok(()),
+                Err(wait) if self.limit.fail_fast => return Err(Error::RateLimitExceeded(wait)),
+                Err(wait) => tokio::time::sleep(wait).await,
+            }
+        }
+    }
+
+    /// Wait until a request can be sent, or fail if the limit is
+    /// exceeded and the limit is set to fail fast.
+    #[cfg(feature = "blocking")]
+    pub(crate) fn acquire(&self) -> Result<(), Error> {
+        loop {
+            match self.try_acquire() {
+                Ok(()) => return Ok(()),
+                Err(wait) if self.limit.fail_fast => return Err(Error::RateLimitExceeded(wait)),
+                Err(wait) => std::thread::sleep(wait),
+            }
+        }
+    }
+}
@@ -0,0 +1,170 @@
+//! Retry policy for transient failures.
+//!
+//! A [`RetryPolicy`] can be set on the client with
+//! [`GuardianContentClientBuilder::retry_policy`](crate::GuardianContentClientBuilder::retry_policy).
+//! Requests are then retried on connection errors, timeouts,
+//! `5xx` responses and `429 Too Many Requests`, waiting between attempts
+//! with a jittered exponential backoff, or for as long as the API asks
+//! to in a `Retry-After` header.
+
+use crate::error::Error;
+use chrono::{DateTime, Utc};
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::StatusCode;
+use std::time::Duration;
+
+/// Controls if and when a failed request is retried.
+///
+/// The default policy makes up to 3 attempts, starting with a backoff
+/// of 500 milliseconds which doubles after each attempt, up to a maximum
+/// of 30 seconds between attempts and 60 seconds in total.
+///
+/// # Example
+/// ```ignore
+/// let client = GuardianContentClient::builder("YOUR_API_KEY")
+///         .retry_policy(
+///             RetryPolicy::default()
+///                 .max_attempts(5)
+///                 .max_elapsed(Duration::from_secs(120)),
+///         )
+///         .build()?;
+/// ```
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RetryPolicy {
+    max_attempts: u32,
+    initial_backoff: Duration,
+    max_backoff: Duration,
+    max_elapsed: Option<Duration>,
+    jitter: bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 3,
+            initial_backoff: Duration::from_millis(500),
+            max_backoff: Duration::from_secs(30),
+            max_elapsed: Some(Duration::from_secs(60)),
+            jitter: true,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries. This is what clients use unless
+    /// a policy is set explicitly.
+    pub fn none() -> Self {
+        Self {
+            max_attempts: 1,
+            ..Self::default()
+        }
+    }
+
+    /// Set the maximum number of attempts, including the first one.
+    /// A value of 0 is treated as 1.
+    pub fn max_attempts(mut self, max_attempts: u32) -> Self {
+        self.max_attempts = max_attempts.max(1);
+        self
+    }
+
+    /// Set the backoff before the first retry.
+    /// The backoff doubles after every attempt.
+    pub fn initial_backoff(mut self, backoff: Duration) -> Self {
+        self.initial_backoff = backoff;
+        self
+    }
+
+    /// Set the maximum backoff between two attempts.
+    pub fn max_backoff(mut self, backoff: Duration) -> Self {
+        self.max_backoff = backoff;
+        self
+    }
+
+    /// Set the total time after which no more attempts are made.
+    /// Passing `None` removes the limit, leaving only `max_attempts`.
+    pub fn max_elapsed(mut self, max_elapsed: Option<Duration>) -> Self {
+        self.max_elapsed = max_elapsed;
+        self
+    }
+
+    /// Enable or disable random jitter on the backoff (enabled by default).
+    /// With jitter, each backoff is a random duration between half and
+    /// the whole of the exponential backoff.
+    pub fn jitter(mut self, jitter: bool) -> Self {
+        self.jitter = jitter;
+        self
+    }
+
+    /// How long to wait before the next attempt, or `None` if
+    /// the request should not be retried.
+    ///
+    /// `attempt` is the number of attempts made so far, and `elapsed`
+    /// the time since the first attempt was made.
+    pub(crate) fn retry_delay(
+        &self,
+        attempt: u32,
+        elapsed: Duration,
+        outcome: Outcome,
+    ) -> Option<Duration> {
+        if attempt >= self.max_attempts {
+            return None;
+        }
+
+        let delay = match outcome {
+            Outcome::Error(Error::ClientError(err)) if err.is_connect() || err.is_timeout() => {
+                self.backoff(attempt)
+            }
+            Outcome::Error(_) => return None,
+            Outcome::Response(status, headers)
+                if status == StatusCode::TOO_MANY_REQUESTS || status.is_server_error() =>
+            {
+                retry_after(headers).unwrap_or_else(|| self.backoff(attempt))
+            }
+            Outcome::Response(..) => return None,
+        };
+
+        match self.max_elapsed {
+            Some(max_elapsed) if elapsed + delay > max_elapsed => None,
+            _ => Some(delay),
+        }
+    }
+
+    /// The exponential backoff after `attempt` attempts.
+    pub(crate) fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(31);
+        let backoff = self
+            .initial_backoff
+            .saturating_mul(1 << exponent)
+            .min(self.max_backoff);
+
+        if self.jitter {
+            let millis = backoff.as_millis() as u64;
+            Duration::from_millis(fastrand::u64(millis / 2..=millis))
+        } else {
+            backoff
+        }
+    }
+}
+
+/// The outcome of an attempt, as far as retries are concerned.
+pub(crate) enum Outcome<'a> {
+    Response(StatusCode, &'a HeaderMap),
+    Error(&'a Error),
+}
+
+/// Parse a `Retry-After` header, which is either a number of
+/// seconds or an HTTP date.
+fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?.trim();
+
+    if let Ok(seconds) = value.parse::<u64>() {
+        return Some(Duration::from_secs(seconds));
+    }
+
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    Some(
+        (date.with_timezone(&Utc) - Utc::now())
+            .to_std()
+            .unwrap_or(Duration::ZERO),
+    )
+}
@@ -0,0 +1,28 @@
+//! Serializers restoring the shape in which the API returns values.
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use serde::ser::SerializeStruct;
+use serde::Serializer;
+
+/// Serialize a date as a `CapiDateTime` object, with a timestamp in
+/// milliseconds and an ISO 8601 string.
+pub(crate) fn option_capi_datetime<S>(
+    value: &Option<DateTime<Utc>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match value {
+        None => serializer.serialize_none(),
+        Some(date) => {
+            let mut capi = serializer.serialize_struct("CapiDateTime", 2)?;
+            capi.serialize_field("dateTime", &date.timestamp_millis())?;
+            capi.serialize_field(
+                "iso8601",
+                &date.to_rfc3339_opts(SecondsFormat::AutoSi, true),
+            )?;
+            capi.end()
+        }
+    }
+}
@@ -0,0 +1,144 @@
+//! Strict deserialization of responses.
+//!
+//! Members of a response which are not modelled by a struct are collected
+//! in its `extra` map. Deserializing a response as [`Strict<T>`] makes such
+//! members fail deserialization instead, to detect changes in the API.
+//! [`GuardianContentClientBuilder::strict`](crate::GuardianContentClientBuilder::strict)
+//! does this for every response of a client.
+//!
+//! Only the structs with an `extra` map are checked: [`SearchResult`],
+//! [`Content`], [`Tag`], [`Section`], [`Block`], [`BlockElement`] and
+//! [`Fields`], wherever they are nested in a response. Unknown members
+//! of other structs, such as assets, elements, references, editions
+//! and atoms, are ignored even in strict mode.
+//!
+//! # Example
+//! ```ignore
+//! let response: Strict<SearchResponse> = serde_json::from_str(body)?;
+//! let results = response.into_inner().results;
+//! ```
+
+use crate::structs::{
+    AtomResponse, Block, BlockElement, Blocks, Content, EditionsResponse, Fields, ItemResponse,
+    SearchResponse, SearchResult, Section, SectionsResponse, Tag, TagsResponse,
+};
+use serde::de::Error;
+use serde::{Deserialize, Deserializer};
+use std::collections::BTreeMap;
+
+/// A value which failed to deserialize if any of its members, or of the
+/// members of the values nested in it, were collected in an `extra` map.
+#[derive(Clone, Debug)]
+pub struct Strict<T>(pub T);
+
+impl<T> Strict<T> {
+    /// The deserialized value.
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<'de, T> Deserialize<'de> for Strict<T>
+where
+    T: Deserialize<'de> + UnknownMembers,
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = T::deserialize(deserializer)?;
+
+        match value.unknown_member() {
+            Some(member) => Err(D::Error::custom(format!("unknown field `{member}`"))),
+            None => Ok(Strict(value)),
+        }
+    }
+}
+
+/// Values which can be checked by [`Strict`] for members collected in
+/// the `extra` map of a struct.
+pub trait UnknownMembers {
+    /// The name of a member not modelled by this value,
+    /// or by a value nested in it, if any.
+    fn unknown_member(&self) -> Option<&str>;
+}
+
+impl UnknownMembers for BTreeMap<String, serde_json::Value> {
+    fn unknown_member(&self) -> Option<&str> {
+        self.keys().next().map(String::as_str)
+    }
+}
+
+impl<T: UnknownMembers> UnknownMembers for Option<T> {
+    fn unknown_member(&self) -> Option<&str> {
+        self.as_ref().and_then(T::unknown_member)
+    }
+}
+
+impl<T: UnknownMembers> UnknownMembers for Vec<T> {
+    fn unknown_member(&self) -> Option<&str> {
+        self.iter().find_map(T::unknown_member)
+    }
+}
+
+/// Implement [`UnknownMembers`] for a struct by checking the given members,
+/// in order.
+macro_rules! impl_unknown_members {
+    ($struct:ty { $($member:ident),* }) => {
+        impl UnknownMembers for $struct {
+            fn unknown_member(&self) -> Option<&str> {
+                None$(.or_else(|| self.$member.unknown_member()))*
+            }
+        }
+    };
+}
+
+impl_unknown_members!(SearchResponse { results, content });
+impl_unknown_members!(TagsResponse { results });
+impl_unknown_members!(SectionsResponse { results });
+impl_unknown_members!(EditionsResponse {});
+impl_unknown_members!(ItemResponse {
+    content,
+    tag,
+    section,
+    results,
+    related_content,
+    story_package,
+    editors_picks,
+    most_viewed,
+    lead_content
+});
+impl_unknown_members!(AtomResponse {});
+impl_unknown_members!(SearchResult {
+    extra,
+    fields,
+    tags,
+    section,
+    blocks
+});
+impl_unknown_members!(Content {
+    extra,
+    fields,
+    tags,
+    section,
+    blocks
+});
+impl_unknown_members!(Tag { extra });
+impl_unknown_members!(Section { extra });
+impl_unknown_members!(Fields { extra });
+impl_unknown_members!(Block { extra, elements });
+impl_unknown_members!(BlockElement { extra });
+
+impl UnknownMembers for Blocks {
+    fn unknown_member(&self) -> Option<&str> {
+        self.main
+            .unknown_member()
+            .or_else(|| self.body.unknown_member())
+            .or_else(|| {
+                self.requested_body_blocks
+                    .iter()
+                    .flat_map(BTreeMap::values)
+                    .find_map(Vec::unknown_member)
+            })
+    }
+}
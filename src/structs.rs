@@ -3,18 +3,27 @@
 //! Many of these structs were autogenerated
 //! by Thrift with manual tweaks using existing
 //! Thrift definitions.
+//!
+//! With the `serde-serialize` feature, the structs also implement
+//! `Serialize`, using the same member names as the API.
 
+use crate::enums::{Field, RightsFilter};
+use crate::ids::{ContentId, EditionId, SectionId, TagId};
 use chrono::Utc;
 use serde::Deserialize;
+#[cfg(feature = "serde-serialize")]
+use serde::Serialize;
 use std::collections::BTreeMap;
 
 #[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
 pub struct Response {
     pub message: Option<String>,
     pub response: Option<SearchResponse>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
 #[serde(rename_all = "camelCase")]
 pub struct SearchResponse {
     pub status: Option<String>,
@@ -30,42 +39,247 @@ pub struct SearchResponse {
     pub content: Option<Content>,
 }
 
+/// The response of the [`Endpoint::Tags`](crate::enums::Endpoint::Tags) endpoint.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct TagsResponse {
+    pub status: Option<String>,
+    pub user_tier: Option<String>,
+    pub total: Option<u32>,
+    pub start_index: Option<u32>,
+    pub page_size: Option<u32>,
+    pub current_page: Option<u32>,
+    pub pages: Option<isize>,
+    pub results: Option<Vec<Tag>>,
+}
+
+/// The response of the [`Endpoint::Sections`](crate::enums::Endpoint::Sections) endpoint.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct SectionsResponse {
+    pub status: Option<String>,
+    pub user_tier: Option<String>,
+    pub total: Option<u32>,
+    pub results: Option<Vec<Section>>,
+}
+
+/// The response of the [`Endpoint::Editions`](crate::enums::Endpoint::Editions) endpoint.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct EditionsResponse {
+    pub status: Option<String>,
+    pub user_tier: Option<String>,
+    pub total: Option<u32>,
+    pub results: Option<Vec<EditionResult>>,
+}
+
+/// The response of the [`Endpoint::SingleItem`](crate::enums::Endpoint::SingleItem) endpoint.
+///
+/// Depending on the item requested, one of `content`, `tag`, `section`
+/// or `edition` is set. Tags, sections and editions also come with
+/// a paginated list of their latest content in `results`, and their
+/// most prominent content in `lead_content`.
+///
+/// The other lists of content are only returned when requested with the
+/// corresponding methods of [`ItemRequestBuilder`](crate::endpoints::ItemRequestBuilder).
+#[derive(Clone, Debug, Default, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct ItemResponse {
+    pub status: Option<String>,
+    pub user_tier: Option<String>,
+    pub total: Option<u32>,
+    pub start_index: Option<u32>,
+    pub page_size: Option<u32>,
+    pub current_page: Option<u32>,
+    pub pages: Option<isize>,
+    pub order_by: Option<String>,
+    pub content: Option<Content>,
+    pub tag: Option<Tag>,
+    pub section: Option<Section>,
+    pub edition: Option<EditionResult>,
+    pub results: Option<Vec<SearchResult>>,
+    pub related_content: Option<Vec<SearchResult>>,
+    pub story_package: Option<Vec<SearchResult>>,
+    pub editors_picks: Option<Vec<SearchResult>>,
+    pub most_viewed: Option<Vec<SearchResult>>,
+    pub lead_content: Option<Vec<SearchResult>>,
+}
+
+/// The response of an atom lookup, made with
+/// [`GuardianContentClient::atom`](crate::GuardianContentClient::atom).
+#[derive(Clone, Debug, Default, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct AtomResponse {
+    pub status: Option<String>,
+    pub user_tier: Option<String>,
+    pub total: Option<u32>,
+    /// The atom, which the API returns under a key named after its type.
+    #[serde(
+        alias = "quiz",
+        alias = "explainer",
+        alias = "qanda",
+        alias = "timeline",
+        alias = "profile",
+        alias = "guide",
+        alias = "media",
+        alias = "review",
+        alias = "chart",
+        alias = "audio",
+        alias = "recipe"
+    )]
+    pub atom: Option<Atom>,
+}
+
+/// The fields of a piece of content, requested with `show_fields`.
+///
+/// The API returns booleans, numbers and dates either natively or as
+/// strings depending on the endpoint, and both forms are accepted.
 #[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
 #[serde(rename_all = "camelCase")]
 pub struct Fields {
-    pub byline: Option<String>,
-    pub short_url: Option<String>,
-    pub trail_text: Option<String>,
     pub headline: Option<String>,
+    pub standfirst: Option<String>,
+    pub trail_text: Option<String>,
+    pub byline: Option<String>,
+    pub byline_html: Option<String>,
+    pub main: Option<String>,
     pub body: Option<String>,
     pub body_text: Option<String>,
-    pub last_modified: Option<chrono::DateTime<Utc>>,
-    pub has_story_package: Option<String>,
-    pub score: Option<String>,
-    pub standfirst: Option<String>,
-    pub show_in_related_content: Option<String>,
-    pub thumbnail: Option<String>,
-    pub wordcount: Option<String>,
-    pub commentable: Option<String>,
-    pub is_premoderated: Option<String>,
-    pub allow_ugc: Option<String>,
+    #[serde(default, deserialize_with = "crate::de::option_number")]
+    pub wordcount: Option<i32>,
+    #[serde(default, deserialize_with = "crate::de::option_number")]
+    pub char_count: Option<i32>,
+    #[serde(default, deserialize_with = "crate::de::option_number")]
+    pub star_rating: Option<i32>,
+    #[serde(default, deserialize_with = "crate::de::option_number")]
+    pub score: Option<f64>,
+    #[serde(default, deserialize_with = "crate::de::option_number")]
+    pub newspaper_page_number: Option<i32>,
+    #[serde(default, deserialize_with = "crate::de::option_datetime")]
+    pub newspaper_edition_date: Option<chrono::DateTime<Utc>>,
     pub publication: Option<String>,
-    pub internal_page_code: Option<String>,
     pub production_office: Option<String>,
-    pub should_hide_adverts: Option<String>,
-    pub live_blogging_now: Option<String>,
+    #[serde(default, deserialize_with = "crate::de::option_datetime")]
+    pub first_publication_date: Option<chrono::DateTime<Utc>>,
+    #[serde(default, deserialize_with = "crate::de::option_datetime")]
+    pub last_modified: Option<chrono::DateTime<Utc>>,
+    #[serde(default, deserialize_with = "crate::de::option_datetime")]
+    pub scheduled_publication_date: Option<chrono::DateTime<Utc>>,
+    #[serde(default, deserialize_with = "crate::de::option_datetime")]
     pub comment_close_date: Option<chrono::DateTime<Utc>>,
-    pub star_rating: Option<String>,
+    #[serde(default, deserialize_with = "crate::de::option_bool")]
+    pub commentable: Option<bool>,
+    #[serde(default, deserialize_with = "crate::de::option_bool")]
+    pub is_premoderated: Option<bool>,
+    #[serde(default, deserialize_with = "crate::de::option_bool")]
+    pub allow_ugc: Option<bool>,
+    #[serde(default, deserialize_with = "crate::de::option_bool")]
+    pub has_story_package: Option<bool>,
+    #[serde(default, deserialize_with = "crate::de::option_bool")]
+    pub show_in_related_content: Option<bool>,
+    #[serde(default, deserialize_with = "crate::de::option_bool")]
+    pub live_blogging_now: Option<bool>,
+    #[serde(default, deserialize_with = "crate::de::option_bool")]
+    pub is_live: Option<bool>,
+    #[serde(default, deserialize_with = "crate::de::option_bool")]
+    pub should_hide_adverts: Option<bool>,
+    #[serde(default, deserialize_with = "crate::de::option_bool")]
+    pub should_hide_reader_revenue: Option<bool>,
+    #[serde(default, deserialize_with = "crate::de::option_bool")]
+    pub show_affiliate_links: Option<bool>,
+    #[serde(default, deserialize_with = "crate::de::option_bool")]
+    pub is_inappropriate_for_sponsorship: Option<bool>,
+    #[serde(default, deserialize_with = "crate::de::option_bool")]
+    pub legally_sensitive: Option<bool>,
+    #[serde(default, deserialize_with = "crate::de::option_bool")]
+    pub sensitive: Option<bool>,
+    pub contributor_bio: Option<String>,
+    pub membership_access: Option<String>,
+    pub display_hint: Option<String>,
+    pub short_url: Option<String>,
+    pub thumbnail: Option<String>,
+    pub lang: Option<String>,
+    #[serde(default, deserialize_with = "crate::de::option_number")]
+    pub internal_page_code: Option<i32>,
+    pub internal_short_id: Option<String>,
+    pub internal_video_code: Option<String>,
+    pub short_social_share_text: Option<String>,
+    pub social_share_text: Option<String>,
+    /// Members of the response not modelled by this struct.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+impl Fields {
+    /// Whether the member requested with `field` was returned.
+    /// [`Field::All`] requests every member, so it never matches a single one.
+    pub fn is_set(&self, field: &Field) -> bool {
+        match field {
+            Field::TrailText => self.trail_text.is_some(),
+            Field::Headline => self.headline.is_some(),
+            Field::ShowInRelatedContent => self.show_in_related_content.is_some(),
+            Field::Body => self.body.is_some(),
+            Field::BodyText => self.body_text.is_some(),
+            Field::LastModified => self.last_modified.is_some(),
+            Field::HasStoryPackage => self.has_story_package.is_some(),
+            Field::Score => self.score.is_some(),
+            Field::Standfirst => self.standfirst.is_some(),
+            Field::ShortUrl => self.short_url.is_some(),
+            Field::Byline => self.byline.is_some(),
+            Field::Thumbnail => self.thumbnail.is_some(),
+            Field::Wordcount => self.wordcount.is_some(),
+            Field::Commentable => self.commentable.is_some(),
+            Field::IsPremoderated => self.is_premoderated.is_some(),
+            Field::AllowUgc => self.allow_ugc.is_some(),
+            Field::Publication => self.publication.is_some(),
+            Field::InternalPageCode => self.internal_page_code.is_some(),
+            Field::ProductionOffice => self.production_office.is_some(),
+            Field::ShouldHideAdverts => self.should_hide_adverts.is_some(),
+            Field::LiveBloggingNow => self.live_blogging_now.is_some(),
+            Field::CommentCloseDate => self.comment_close_date.is_some(),
+            Field::StarRating => self.star_rating.is_some(),
+            Field::Main => self.main.is_some(),
+            Field::NewspaperPageNumber => self.newspaper_page_number.is_some(),
+            Field::ContributorBio => self.contributor_bio.is_some(),
+            Field::MembershipAccess => self.membership_access.is_some(),
+            Field::DisplayHint => self.display_hint.is_some(),
+            Field::FirstPublicationDate => self.first_publication_date.is_some(),
+            Field::IsInappropriateForSponsorship => self.is_inappropriate_for_sponsorship.is_some(),
+            Field::NewspaperEditionDate => self.newspaper_edition_date.is_some(),
+            Field::ScheduledPublicationDate => self.scheduled_publication_date.is_some(),
+            Field::LegallySensitive => self.legally_sensitive.is_some(),
+            Field::Sensitive => self.sensitive.is_some(),
+            Field::Lang => self.lang.is_some(),
+            Field::IsLive => self.is_live.is_some(),
+            Field::InternalShortId => self.internal_short_id.is_some(),
+            Field::ShortSocialShareText => self.short_social_share_text.is_some(),
+            Field::SocialShareText => self.social_share_text.is_some(),
+            Field::CharCount => self.char_count.is_some(),
+            Field::InternalVideoCode => self.internal_video_code.is_some(),
+            Field::ShouldHideReaderRevenue => self.should_hide_reader_revenue.is_some(),
+            Field::ShowAffiliateLinks => self.show_affiliate_links.is_some(),
+            Field::BylineHtml => self.byline_html.is_some(),
+            Field::All => false,
+        }
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
 #[serde(rename_all = "camelCase")]
 pub struct Tag {
-    pub id: String,
+    pub id: TagId,
     pub r#type: String,
     pub web_title: String,
     pub web_url: String,
     pub api_url: String,
+    #[serde(default)]
     pub references: Vec<Reference>,
     pub bio: Option<String>,
     pub byline_image_url: Option<String>,
@@ -74,7 +288,7 @@ pub struct Tag {
     pub last_name: Option<String>,
     pub email_address: Option<String>,
     pub twitter_handle: Option<String>,
-    pub section_id: Option<String>,
+    pub section_id: Option<SectionId>,
     pub section_name: Option<String>,
     pub description: Option<String>,
     pub paid_content_type: Option<String>,
@@ -85,14 +299,18 @@ pub struct Tag {
     pub entity_ids: Option<Vec<String>>,
     pub campaign_information_type: Option<String>,
     pub internal_name: Option<String>,
+    /// Members of the response not modelled by this struct.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
 #[serde(rename_all = "camelCase")]
 pub struct SearchResult {
-    pub id: String,
+    pub id: ContentId,
     pub r#type: Option<String>,
-    pub section_id: Option<String>,
+    pub section_id: Option<SectionId>,
     pub section_name: Option<String>,
     pub web_publication_date: Option<chrono::DateTime<Utc>>,
     pub web_title: String,
@@ -106,25 +324,37 @@ pub struct SearchResult {
     pub section: Option<Section>,
     pub blocks: Option<Blocks>,
     pub editions: Option<Vec<Edition>>,
+    pub atoms: Option<Atoms>,
+    pub elements: Option<Vec<Element>>,
+    pub rights: Option<Rights>,
+    /// Members of the response not modelled by this struct.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
 pub struct Reference {
     pub id: String,
     pub r#type: String,
 }
 
 #[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
 #[serde(rename_all = "camelCase")]
 pub struct Section {
-    pub id: String,
+    pub id: SectionId,
     pub web_title: String,
     pub web_url: String,
     pub api_url: String,
     pub editions: Vec<Edition>,
+    /// Members of the response not modelled by this struct.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
 #[serde(rename_all = "camelCase")]
 pub struct Edition {
     pub id: String,
@@ -134,7 +364,22 @@ pub struct Edition {
     pub code: String,
 }
 
+/// An edition, as returned by the
+/// [`Endpoint::Editions`](crate::enums::Endpoint::Editions) endpoint.
 #[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct EditionResult {
+    pub id: EditionId,
+    pub path: String,
+    pub edition: String,
+    pub web_title: String,
+    pub web_url: String,
+    pub api_url: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
 #[serde(rename_all = "camelCase")]
 pub struct Blocks {
     pub main: Option<Block>,
@@ -144,6 +389,7 @@ pub struct Blocks {
 }
 
 #[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
 #[serde(rename_all = "camelCase")]
 pub struct Block {
     pub id: String,
@@ -152,42 +398,261 @@ pub struct Block {
     pub title: Option<String>,
     pub attributes: BlockAttributes,
     pub published: bool,
-    pub created_date: Option<serde_json::Value>,
-    pub first_published_date: Option<serde_json::Value>,
-    pub published_date: Option<serde_json::Value>,
-    pub last_modified_date: Option<serde_json::Value>,
+    #[serde(default, deserialize_with = "crate::de::option_datetime")]
+    #[cfg_attr(
+        feature = "serde-serialize",
+        serde(serialize_with = "crate::ser::option_capi_datetime")
+    )]
+    pub created_date: Option<chrono::DateTime<Utc>>,
+    #[serde(default, deserialize_with = "crate::de::option_datetime")]
+    #[cfg_attr(
+        feature = "serde-serialize",
+        serde(serialize_with = "crate::ser::option_capi_datetime")
+    )]
+    pub first_published_date: Option<chrono::DateTime<Utc>>,
+    #[serde(default, deserialize_with = "crate::de::option_datetime")]
+    #[cfg_attr(
+        feature = "serde-serialize",
+        serde(serialize_with = "crate::ser::option_capi_datetime")
+    )]
+    pub published_date: Option<chrono::DateTime<Utc>>,
+    #[serde(default, deserialize_with = "crate::de::option_datetime")]
+    #[cfg_attr(
+        feature = "serde-serialize",
+        serde(serialize_with = "crate::ser::option_capi_datetime")
+    )]
+    pub last_modified_date: Option<chrono::DateTime<Utc>>,
     pub contributors: Vec<String>,
     pub elements: Vec<BlockElement>,
+    /// Members of the response not modelled by this struct.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
 }
 
+/// An element of a [`Block`], such as a paragraph of text, an image or an embed.
 #[derive(Clone, Debug, Deserialize)]
-#[serde(rename_all = "camelCase")]
+#[cfg_attr(
+    feature = "serde-serialize",
+    derive(Serialize),
+    serde(into = "RawBlockElement")
+)]
+#[serde(try_from = "RawBlockElement")]
 pub struct BlockElement {
     pub r#type: String,
     pub assets: Vec<Asset>,
-    pub text_type_data: Option<TextElementFields>,
-    pub video_type_data: Option<VideoElementFields>,
-    pub tweet_type_data: Option<TweetElementFields>,
-    pub image_type_data: Option<ImageElementFields>,
-    pub audio_type_data: Option<AudioElementFields>,
-    pub pullquote_type_data: Option<PullquoteElementFields>,
-    pub interactive_type_data: Option<InteractiveElementFields>,
-    pub map_type_data: Option<StandardElementFields>,
-    pub document_type_data: Option<StandardElementFields>,
-    pub table_type_data: Option<StandardElementFields>,
-    pub witness_type_data: Option<WitnessElementFields>,
-    pub rich_link_type_data: Option<RichLinkElementFields>,
-    pub membership_type_data: Option<MembershipElementFields>,
-    pub embed_type_data: Option<EmbedElementFields>,
-    pub instagram_type_data: Option<InstagramElementFields>,
-    pub comment_type_data: Option<CommentElementFields>,
-    pub vine_type_data: Option<VineElementFields>,
-    pub content_atom_type_data: Option<ContentAtomElementFields>,
     pub tracking: Option<Box<EmbedTracking>>,
-    pub code_type_data: Option<CodeElementFields>,
+    pub data: ElementData,
+    /// Members of the response not modelled by this struct.
+    pub extra: BTreeMap<String, serde_json::Value>,
+}
+
+/// The data of a [`BlockElement`], depending on its type.
+///
+/// The data of elements of types not supported by this crate is kept
+/// as raw JSON in [`ElementData::Unknown`], which is `null` when an
+/// element has no data matching its type.
+/// Payloads are boxed, as some of them are large.
+#[derive(Clone, Debug)]
+pub enum ElementData {
+    Text(Box<TextElementFields>),
+    Image(Box<ImageElementFields>),
+    Video(Box<VideoElementFields>),
+    Tweet(Box<TweetElementFields>),
+    Audio(Box<AudioElementFields>),
+    Pullquote(Box<PullquoteElementFields>),
+    Interactive(Box<InteractiveElementFields>),
+    Map(Box<StandardElementFields>),
+    Document(Box<StandardElementFields>),
+    Table(Box<StandardElementFields>),
+    Witness(Box<WitnessElementFields>),
+    RichLink(Box<RichLinkElementFields>),
+    Membership(Box<MembershipElementFields>),
+    Embed(Box<EmbedElementFields>),
+    Instagram(Box<InstagramElementFields>),
+    Comment(Box<CommentElementFields>),
+    Vine(Box<VineElementFields>),
+    ContentAtom(Box<ContentAtomElementFields>),
+    Code(Box<CodeElementFields>),
+    Unknown(serde_json::Value),
+}
+
+/// A block element as returned by the API, with the data of each type
+/// of element in a separate member.
+#[derive(Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Default, Serialize))]
+#[serde(rename_all = "camelCase")]
+struct RawBlockElement {
+    r#type: String,
+    #[serde(default)]
+    assets: Vec<Asset>,
+    tracking: Option<Box<EmbedTracking>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    text_type_data: Option<TextElementFields>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    image_type_data: Option<ImageElementFields>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    video_type_data: Option<VideoElementFields>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tweet_type_data: Option<TweetElementFields>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    audio_type_data: Option<AudioElementFields>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pullquote_type_data: Option<PullquoteElementFields>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    interactive_type_data: Option<InteractiveElementFields>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    map_type_data: Option<StandardElementFields>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    document_type_data: Option<StandardElementFields>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    table_type_data: Option<StandardElementFields>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    witness_type_data: Option<WitnessElementFields>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    rich_link_type_data: Option<RichLinkElementFields>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    membership_type_data: Option<MembershipElementFields>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    embed_type_data: Option<EmbedElementFields>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    instagram_type_data: Option<InstagramElementFields>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    comment_type_data: Option<CommentElementFields>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    vine_type_data: Option<VineElementFields>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    content_atom_type_data: Option<ContentAtomElementFields>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    code_type_data: Option<CodeElementFields>,
+    #[serde(flatten)]
+    other: BTreeMap<String, serde_json::Value>,
+}
+
+impl TryFrom<RawBlockElement> for BlockElement {
+    type Error = String;
+
+    fn try_from(mut raw: RawBlockElement) -> Result<Self, Self::Error> {
+        let data = match raw.r#type.as_str() {
+            "text" => raw
+                .text_type_data
+                .map(|data| ElementData::Text(Box::new(data))),
+            "image" => raw
+                .image_type_data
+                .map(|data| ElementData::Image(Box::new(data))),
+            "video" => raw
+                .video_type_data
+                .map(|data| ElementData::Video(Box::new(data))),
+            "tweet" => raw
+                .tweet_type_data
+                .map(|data| ElementData::Tweet(Box::new(data))),
+            "audio" => raw
+                .audio_type_data
+                .map(|data| ElementData::Audio(Box::new(data))),
+            "pullquote" => raw
+                .pullquote_type_data
+                .map(|data| ElementData::Pullquote(Box::new(data))),
+            "interactive" => raw
+                .interactive_type_data
+                .map(|data| ElementData::Interactive(Box::new(data))),
+            "map" => raw
+                .map_type_data
+                .map(|data| ElementData::Map(Box::new(data))),
+            "document" => raw
+                .document_type_data
+                .map(|data| ElementData::Document(Box::new(data))),
+            "table" => raw
+                .table_type_data
+                .map(|data| ElementData::Table(Box::new(data))),
+            "witness" => raw
+                .witness_type_data
+                .map(|data| ElementData::Witness(Box::new(data))),
+            "rich-link" => raw
+                .rich_link_type_data
+                .map(|data| ElementData::RichLink(Box::new(data))),
+            "membership" => raw
+                .membership_type_data
+                .map(|data| ElementData::Membership(Box::new(data))),
+            "embed" => raw
+                .embed_type_data
+                .map(|data| ElementData::Embed(Box::new(data))),
+            "instagram" => raw
+                .instagram_type_data
+                .map(|data| ElementData::Instagram(Box::new(data))),
+            "comment" => raw
+                .comment_type_data
+                .map(|data| ElementData::Comment(Box::new(data))),
+            "vine" => raw
+                .vine_type_data
+                .map(|data| ElementData::Vine(Box::new(data))),
+            "contentatom" => raw
+                .content_atom_type_data
+                .map(|data| ElementData::ContentAtom(Box::new(data))),
+            "code" => raw
+                .code_type_data
+                .map(|data| ElementData::Code(Box::new(data))),
+            _ => None,
+        };
+
+        // The data of elements of unknown types is under `<type>TypeData`
+        let data = data.unwrap_or_else(|| {
+            let key = format!("{}TypeData", crate::de::camel_case(&raw.r#type));
+            ElementData::Unknown(raw.other.remove(&key).unwrap_or_default())
+        });
+
+        Ok(Self {
+            r#type: raw.r#type,
+            assets: raw.assets,
+            tracking: raw.tracking,
+            data,
+            extra: raw.other,
+        })
+    }
+}
+
+#[cfg(feature = "serde-serialize")]
+impl From<BlockElement> for RawBlockElement {
+    fn from(element: BlockElement) -> Self {
+        let mut raw = RawBlockElement {
+            assets: element.assets,
+            tracking: element.tracking,
+            other: element.extra,
+            ..Default::default()
+        };
+
+        match element.data {
+            ElementData::Text(data) => raw.text_type_data = Some(*data),
+            ElementData::Image(data) => raw.image_type_data = Some(*data),
+            ElementData::Video(data) => raw.video_type_data = Some(*data),
+            ElementData::Tweet(data) => raw.tweet_type_data = Some(*data),
+            ElementData::Audio(data) => raw.audio_type_data = Some(*data),
+            ElementData::Pullquote(data) => raw.pullquote_type_data = Some(*data),
+            ElementData::Interactive(data) => raw.interactive_type_data = Some(*data),
+            ElementData::Map(data) => raw.map_type_data = Some(*data),
+            ElementData::Document(data) => raw.document_type_data = Some(*data),
+            ElementData::Table(data) => raw.table_type_data = Some(*data),
+            ElementData::Witness(data) => raw.witness_type_data = Some(*data),
+            ElementData::RichLink(data) => raw.rich_link_type_data = Some(*data),
+            ElementData::Membership(data) => raw.membership_type_data = Some(*data),
+            ElementData::Embed(data) => raw.embed_type_data = Some(*data),
+            ElementData::Instagram(data) => raw.instagram_type_data = Some(*data),
+            ElementData::Comment(data) => raw.comment_type_data = Some(*data),
+            ElementData::Vine(data) => raw.vine_type_data = Some(*data),
+            ElementData::ContentAtom(data) => raw.content_atom_type_data = Some(*data),
+            ElementData::Code(data) => raw.code_type_data = Some(*data),
+            ElementData::Unknown(serde_json::Value::Null) => {}
+            ElementData::Unknown(data) => {
+                let key = format!("{}TypeData", crate::de::camel_case(&element.r#type));
+                raw.other.insert(key, data);
+            }
+        }
+
+        raw.r#type = element.r#type;
+        raw
+    }
 }
 
 #[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
 #[serde(rename_all = "camelCase")]
 pub struct TextElementFields {
     pub html: Option<String>,
@@ -195,6 +660,7 @@ pub struct TextElementFields {
 }
 
 #[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
 #[serde(rename_all = "camelCase")]
 pub struct VideoElementFields {
     pub url: Option<String>,
@@ -220,6 +686,7 @@ pub struct VideoElementFields {
 }
 
 #[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
 #[serde(rename_all = "camelCase")]
 pub struct TweetElementFields {
     pub source: Option<String>,
@@ -232,6 +699,7 @@ pub struct TweetElementFields {
 }
 
 #[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
 #[serde(rename_all = "camelCase")]
 pub struct ImageElementFields {
     pub caption: Option<String>,
@@ -251,6 +719,7 @@ pub struct ImageElementFields {
 }
 
 #[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
 #[serde(rename_all = "camelCase")]
 pub struct AudioElementFields {
     pub html: Option<String>,
@@ -268,6 +737,7 @@ pub struct AudioElementFields {
 }
 
 #[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
 #[serde(rename_all = "camelCase")]
 pub struct PullquoteElementFields {
     pub html: Option<String>,
@@ -278,6 +748,7 @@ pub struct PullquoteElementFields {
 }
 
 #[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
 #[serde(rename_all = "camelCase")]
 pub struct InteractiveElementFields {
     pub url: Option<String>,
@@ -295,6 +766,7 @@ pub struct InteractiveElementFields {
 }
 
 #[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
 #[serde(rename_all = "camelCase")]
 pub struct StandardElementFields {
     pub url: Option<String>,
@@ -313,6 +785,7 @@ pub struct StandardElementFields {
 }
 
 #[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
 #[serde(rename_all = "camelCase")]
 pub struct WitnessElementFields {
     pub url: Option<String>,
@@ -345,6 +818,7 @@ pub struct WitnessElementFields {
 }
 
 #[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
 #[serde(rename_all = "camelCase")]
 pub struct RichLinkElementFields {
     pub url: Option<String>,
@@ -356,6 +830,7 @@ pub struct RichLinkElementFields {
 }
 
 #[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
 #[serde(rename_all = "camelCase")]
 pub struct MembershipElementFields {
     pub original_url: Option<String>,
@@ -373,6 +848,7 @@ pub struct MembershipElementFields {
 }
 
 #[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
 #[serde(rename_all = "camelCase")]
 pub struct EmbedElementFields {
     pub html: Option<String>,
@@ -386,6 +862,7 @@ pub struct EmbedElementFields {
 }
 
 #[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
 #[serde(rename_all = "camelCase")]
 pub struct InstagramElementFields {
     pub original_url: String,
@@ -402,6 +879,7 @@ pub struct InstagramElementFields {
 }
 
 #[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
 #[serde(rename_all = "camelCase")]
 pub struct CommentElementFields {
     pub source: Option<String>,
@@ -418,6 +896,7 @@ pub struct CommentElementFields {
 }
 
 #[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
 #[serde(rename_all = "camelCase")]
 pub struct VineElementFields {
     pub original_url: String,
@@ -435,6 +914,7 @@ pub struct VineElementFields {
 }
 
 #[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
 #[serde(rename_all = "camelCase")]
 pub struct ContentAtomElementFields {
     pub atom_id: String,
@@ -443,19 +923,60 @@ pub struct ContentAtomElementFields {
 }
 
 #[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
 #[serde(rename_all = "camelCase")]
 pub struct EmbedTracking {
     pub tracks: String,
 }
 
 #[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
 #[serde(rename_all = "camelCase")]
 pub struct CodeElementFields {
     pub html: String,
     pub language: String,
 }
 
+/// A media element of a piece of content, e.g. its main image,
+/// with all of its renditions in `assets`.
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct Element {
+    pub id: String,
+    pub relation: String,
+    pub r#type: String,
+    pub gallery_index: Option<i32>,
+    #[serde(default)]
+    pub assets: Vec<Asset>,
+}
+
+/// The syndication rights of a piece of content.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct Rights {
+    #[serde(default)]
+    pub syndicatable: bool,
+    #[serde(default)]
+    pub subscription_databases: bool,
+    #[serde(default)]
+    pub developer_community: bool,
+}
+
+impl Rights {
+    /// Whether the content can be reused in the given way.
+    pub fn allows(&self, rights: RightsFilter) -> bool {
+        match rights {
+            RightsFilter::Syndicatable => self.syndicatable,
+            RightsFilter::SubscriptionDatabases => self.subscription_databases,
+            RightsFilter::DeveloperCommunity => self.developer_community,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
 #[serde(rename_all = "camelCase")]
 pub struct Asset {
     pub r#type: String,
@@ -465,6 +986,7 @@ pub struct Asset {
 }
 
 #[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
 #[serde(rename_all = "camelCase")]
 pub struct AssetFields {
     pub aspect_ratio: Option<String>,
@@ -521,7 +1043,8 @@ pub struct AssetFields {
     pub author_witness_profile_url: Option<String>,
     pub author_guardian_profile_url: Option<String>,
     pub api_url: Option<String>,
-    pub date_created: Option<serde_json::Value>,
+    #[serde(default, deserialize_with = "crate::de::option_datetime")]
+    pub date_created: Option<chrono::DateTime<Utc>>,
     pub youtube_url: Option<String>,
     pub youtube_source: Option<String>,
     pub youtube_title: Option<String>,
@@ -532,12 +1055,15 @@ pub struct AssetFields {
     pub location: Option<String>,
     pub identifier: Option<String>,
     pub price: Option<String>,
+    #[serde(default, deserialize_with = "crate::de::option_datetime")]
     pub start: Option<chrono::DateTime<Utc>>,
+    #[serde(default, deserialize_with = "crate::de::option_datetime")]
     pub end: Option<chrono::DateTime<Utc>>,
     pub safe_embed_code: Option<bool>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
 #[serde(rename_all = "camelCase")]
 pub struct CapiDateTime {
     pub date_time: i64,
@@ -545,6 +1071,7 @@ pub struct CapiDateTime {
 }
 
 #[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
 #[serde(rename_all = "camelCase")]
 #[allow(dead_code)]
 pub struct BlockAttributes {
@@ -556,12 +1083,14 @@ pub struct BlockAttributes {
 }
 
 #[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
 #[serde(rename_all = "camelCase")]
 pub struct MembershipPlaceholder {
     pub campaign_code: Option<String>,
 }
 
 #[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
 #[serde(rename_all = "camelCase")]
 #[allow(dead_code)]
 pub struct User {
@@ -571,73 +1100,605 @@ pub struct User {
 }
 
 #[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
 #[serde(rename_all = "camelCase")]
 pub struct Content {
-    pub id: String,
+    pub id: ContentId,
     pub r#type: String,
-    pub section_id: Option<String>,
+    pub section_id: Option<SectionId>,
     pub section_name: Option<String>,
     pub web_publication_date: Option<chrono::DateTime<Utc>>,
     pub web_title: String,
     pub web_url: String,
     pub api_url: String,
-    pub fields: Option<ContentFields>,
+    pub fields: Option<Fields>,
+    #[serde(default)]
     pub tags: Vec<Tag>,
-    // pub elements: Option<Vec<Element>>,
+    pub elements: Option<Vec<Element>>,
     pub references: Option<Vec<Reference>>,
     pub is_expired: Option<bool>,
     pub blocks: Option<Blocks>,
-    // pub rights: Option<Rights>,
+    pub rights: Option<Rights>,
     pub section: Option<Section>,
     pub is_gone: Option<bool>,
     pub is_hosted: bool,
     pub pillar_id: Option<String>,
     pub pillar_name: Option<String>,
+    pub atoms: Option<Atoms>,
+    /// Members of the response not modelled by this struct.
+    #[serde(flatten)]
+    pub extra: BTreeMap<String, serde_json::Value>,
 }
 
+/// The fields of a [`Content`], which are now the same [`Fields`] as
+/// those of a [`SearchResult`]. Kept so that existing code still compiles.
+pub type ContentFields = Fields;
+
+/// The atoms embedded in a piece of content, grouped by type.
+/// Only returned when requested with `show_atoms`.
 #[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
 #[serde(rename_all = "camelCase")]
-pub struct ContentFields {
-    pub headline: Option<String>,
-    pub standfirst: Option<String>,
-    pub trail_text: Option<String>,
-    pub byline: Option<String>,
-    pub main: Option<String>,
+pub struct Atoms {
+    pub quizzes: Option<Vec<Atom>>,
+    pub explainers: Option<Vec<Atom>>,
+    pub qandas: Option<Vec<Atom>>,
+    pub timelines: Option<Vec<Atom>>,
+    pub profiles: Option<Vec<Atom>>,
+    pub guides: Option<Vec<Atom>>,
+    pub media: Option<Vec<Atom>>,
+    pub reviews: Option<Vec<Atom>>,
+    pub charts: Option<Vec<Atom>>,
+    pub audios: Option<Vec<Atom>>,
+    pub recipes: Option<Vec<Atom>>,
+}
+
+/// A content atom, i.e. a self-contained piece of content such as a quiz
+/// or an explainer, which can be embedded in articles.
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct Atom {
+    pub id: String,
+    pub atom_type: String,
+    #[serde(default)]
+    pub labels: Vec<String>,
+    pub default_html: Option<String>,
+    pub title: Option<String>,
+    pub data: AtomData,
+    pub content_change_details: Option<ContentChangeDetails>,
+    #[serde(default)]
+    pub commissioning_desks: Vec<String>,
+}
+
+/// The payload of an atom, depending on its type.
+///
+/// Atoms of types not supported by this crate are kept as raw JSON
+/// in [`AtomData::Other`], while a malformed atom of a supported type
+/// fails to deserialize. Payloads are boxed, as some of them are large.
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase", try_from = "serde_json::Value")]
+pub enum AtomData {
+    Quiz(Box<QuizAtom>),
+    Explainer(Box<ExplainerAtom>),
+    Qanda(Box<QAndAAtom>),
+    Timeline(Box<TimelineAtom>),
+    Profile(Box<ProfileAtom>),
+    Guide(Box<GuideAtom>),
+    Media(Box<MediaAtom>),
+    Review(Box<ReviewAtom>),
+    Chart(Box<ChartAtom>),
+    Audio(Box<AudioAtom>),
+    Recipe(Box<RecipeAtom>),
+    #[serde(untagged)]
+    Other(serde_json::Value),
+}
+
+impl TryFrom<serde_json::Value> for AtomData {
+    type Error = serde_json::Error;
+
+    fn try_from(value: serde_json::Value) -> Result<Self, Self::Error> {
+        let payload = match &value {
+            serde_json::Value::Object(map) if map.len() == 1 => map.iter().next(),
+            _ => None,
+        };
+        let Some((atom_type, payload)) = payload else {
+            return Ok(AtomData::Other(value));
+        };
+
+        let payload = payload.clone();
+        Ok(match atom_type.as_str() {
+            "quiz" => AtomData::Quiz(serde_json::from_value(payload)?),
+            "explainer" => AtomData::Explainer(serde_json::from_value(payload)?),
+            "qanda" => AtomData::Qanda(serde_json::from_value(payload)?),
+            "timeline" => AtomData::Timeline(serde_json::from_value(payload)?),
+            "profile" => AtomData::Profile(serde_json::from_value(payload)?),
+            "guide" => AtomData::Guide(serde_json::from_value(payload)?),
+            "media" => AtomData::Media(serde_json::from_value(payload)?),
+            "review" => AtomData::Review(serde_json::from_value(payload)?),
+            "chart" => AtomData::Chart(serde_json::from_value(payload)?),
+            "audio" => AtomData::Audio(serde_json::from_value(payload)?),
+            "recipe" => AtomData::Recipe(serde_json::from_value(payload)?),
+            _ => AtomData::Other(value),
+        })
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct ContentChangeDetails {
+    pub last_modified: Option<ChangeRecord>,
+    pub created: Option<ChangeRecord>,
+    pub published: Option<ChangeRecord>,
+    pub revision: Option<i64>,
+    pub taken_down: Option<ChangeRecord>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct ChangeRecord {
+    pub date: i64,
+    pub user: Option<User>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct QuizAtom {
+    pub id: Option<String>,
+    pub title: String,
+    pub reveal_at_end: Option<bool>,
+    pub published: Option<bool>,
+    pub quiz_type: Option<String>,
+    pub default_columns: Option<i32>,
+    pub content: QuizContent,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct QuizContent {
+    #[serde(default)]
+    pub questions: Vec<QuizQuestion>,
+    pub result_groups: Option<QuizResultGroups>,
+    pub result_buckets: Option<QuizResultBuckets>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct QuizQuestion {
+    pub id: Option<String>,
+    pub question_text: String,
+    #[serde(default)]
+    pub assets: Vec<QuizAsset>,
+    #[serde(default)]
+    pub answers: Vec<QuizAnswer>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct QuizAnswer {
+    pub id: Option<String>,
+    pub answer_text: String,
+    #[serde(default)]
+    pub assets: Vec<QuizAsset>,
+    pub weight: Option<i32>,
+    pub reveal_text: Option<String>,
+    #[serde(default)]
+    pub bucket: Vec<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct QuizAsset {
+    pub r#type: String,
+    pub data: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct QuizResultGroups {
+    #[serde(default)]
+    pub groups: Vec<QuizResultGroup>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct QuizResultGroup {
+    pub id: Option<String>,
+    pub title: String,
+    pub share: Option<String>,
+    pub min_score: i32,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct QuizResultBuckets {
+    #[serde(default)]
+    pub buckets: Vec<QuizResultBucket>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct QuizResultBucket {
+    pub id: String,
+    pub title: String,
+    pub share: Option<String>,
+    pub description: Option<String>,
+    #[serde(default)]
+    pub assets: Vec<QuizAsset>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct ExplainerAtom {
+    pub title: String,
+    pub body: String,
+    pub display_type: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct QAndAAtom {
+    pub type_label: Option<String>,
+    pub event_image: Option<AtomImage>,
+    pub item: QAndAItem,
+    pub question: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct QAndAItem {
+    pub title: Option<String>,
+    pub body: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct TimelineAtom {
+    pub type_label: Option<String>,
+    #[serde(default)]
+    pub events: Vec<TimelineItem>,
+    pub description: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct TimelineItem {
+    pub title: String,
+    pub date: i64,
     pub body: Option<String>,
-    pub newspaper_page_number: Option<i32>,
-    pub star_rating: Option<i32>,
-    pub contributor_bio: Option<String>,
-    pub membership_access: Option<String>,
-    pub wordcount: Option<i32>,
-    pub comment_close_date: Option<CapiDateTime>,
-    pub commentable: Option<bool>,
-    pub display_hint: Option<String>,
-    pub first_publication_date: Option<CapiDateTime>,
-    pub has_story_package: Option<bool>,
-    pub is_inappropriate_for_sponsorship: Option<bool>,
-    pub is_premoderated: Option<bool>,
-    pub last_modified: Option<CapiDateTime>,
-    pub live_blogging_now: Option<bool>,
-    pub newspaper_edition_date: Option<CapiDateTime>,
-    pub production_office: Option<String>,
-    pub publication: Option<String>,
-    pub scheduled_publication_date: Option<CapiDateTime>,
-    pub short_url: Option<String>,
-    pub should_hide_adverts: Option<bool>,
-    pub show_in_related_content: Option<bool>,
-    pub thumbnail: Option<String>,
-    pub legally_sensitive: Option<bool>,
-    pub allow_ugc: Option<bool>,
-    pub sensitive: Option<bool>,
-    pub lang: Option<String>,
-    pub is_live: Option<bool>,
-    pub internal_short_id: Option<String>,
-    pub short_social_share_text: Option<String>,
-    pub social_share_text: Option<String>,
-    pub body_text: Option<String>,
-    pub char_count: Option<i32>,
-    pub internal_video_code: Option<String>,
-    pub should_hide_reader_revenue: Option<bool>,
-    pub show_affiliate_links: Option<bool>,
-    pub byline_html: Option<String>,
+    pub to_date: Option<i64>,
+    pub date_format: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileAtom {
+    pub type_label: Option<String>,
+    pub headshot: Option<AtomImage>,
+    #[serde(default)]
+    pub items: Vec<ProfileItem>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct ProfileItem {
+    pub title: Option<String>,
+    pub body: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct GuideAtom {
+    pub type_label: Option<String>,
+    pub guide_image: Option<AtomImage>,
+    #[serde(default)]
+    pub items: Vec<GuideItem>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct GuideItem {
+    pub title: Option<String>,
+    pub body: String,
+}
+
+/// An image used by an atom, in several sizes.
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct AtomImage {
+    #[serde(default)]
+    pub assets: Vec<AtomImageAsset>,
+    pub master: Option<AtomImageAsset>,
+    pub media_id: String,
+    pub source: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct AtomImageAsset {
+    pub mime_type: Option<String>,
+    pub file: String,
+    pub dimensions: Option<AtomImageDimensions>,
+    pub size: Option<i64>,
+    pub aspect_ratio: Option<String>,
+    pub credit: Option<String>,
+    pub copyright: Option<String>,
+    pub source: Option<String>,
+    pub photographer: Option<String>,
+    pub supplier_reference: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct AtomImageDimensions {
+    pub height: i32,
+    pub width: i32,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct MediaAtom {
+    pub title: String,
+    pub category: String,
+    #[serde(default)]
+    pub assets: Vec<MediaAtomAsset>,
+    pub active_version: Option<i64>,
+    pub duration: Option<i64>,
+    pub source: Option<String>,
+    pub poster_url: Option<String>,
+    pub description: Option<String>,
+    pub trail_text: Option<String>,
+    #[serde(default)]
+    pub byline: Vec<String>,
+    #[serde(default)]
+    pub keywords: Vec<String>,
+    pub poster_image: Option<AtomImage>,
+    pub trail_image: Option<AtomImage>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct MediaAtomAsset {
+    pub asset_type: String,
+    pub version: i64,
+    pub id: String,
+    pub platform: String,
+    pub mime_type: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewAtom {
+    pub review_type: String,
+    pub reviewer: String,
+    pub rating: ReviewRating,
+    pub review_snippet: String,
+    pub entity_id: String,
+    pub source_article_id: Option<String>,
+    #[serde(default)]
+    pub images: Vec<AtomImage>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct ReviewRating {
+    pub max_rating: i32,
+    pub actual_rating: i32,
+    pub min_rating: i32,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct ChartAtom {
+    pub chart_type: String,
+    pub title: String,
+    pub furniture: Option<ChartFurniture>,
+    pub tabular_data: Option<ChartTabularData>,
+    #[serde(default)]
+    pub series_colour: Vec<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct ChartFurniture {
+    pub description: Option<String>,
+    pub source: Option<String>,
+    pub source_url: Option<String>,
+    pub axis_x_label: Option<String>,
+    pub axis_y_label: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct ChartTabularData {
+    #[serde(default)]
+    pub column_names: Vec<String>,
+    #[serde(default)]
+    pub row_data: Vec<ChartRow>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct ChartRow {
+    #[serde(default)]
+    pub values: Vec<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct AudioAtom {
+    pub kicker: String,
+    pub cover_url: String,
+    pub track_url: String,
+    pub duration: i32,
+    pub content_id: String,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct RecipeAtom {
+    pub title: String,
+    pub tags: Option<RecipeTags>,
+    pub time: Option<RecipeTime>,
+    pub serves: Option<RecipeServes>,
+    #[serde(default)]
+    pub ingredients_lists: Vec<RecipeIngredientsList>,
+    #[serde(default)]
+    pub steps: Vec<String>,
+    #[serde(default)]
+    pub credits: Vec<String>,
+    #[serde(default)]
+    pub images: Vec<AtomImage>,
+    pub source_article_id: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct RecipeTags {
+    #[serde(default)]
+    pub cuisine: Vec<String>,
+    #[serde(default)]
+    pub category: Vec<String>,
+    #[serde(default)]
+    pub celebration: Vec<String>,
+    #[serde(default)]
+    pub dietary: Vec<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct RecipeTime {
+    pub preparation: Option<i16>,
+    pub cooking: Option<i16>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct RecipeServes {
+    pub r#type: String,
+    pub from: i16,
+    pub to: i16,
+    pub unit: Option<String>,
 }
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct RecipeIngredientsList {
+    pub title: Option<String>,
+    #[serde(default)]
+    pub ingredients: Vec<RecipeIngredient>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct RecipeIngredient {
+    pub item: String,
+    pub comment: Option<String>,
+    pub quantity: Option<f64>,
+    pub quantity_range: Option<RecipeRange>,
+    pub unit: Option<String>,
+    pub raw: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[cfg_attr(feature = "serde-serialize", derive(Serialize))]
+#[serde(rename_all = "camelCase")]
+pub struct RecipeRange {
+    pub from: f64,
+    pub to: f64,
+}
+
+/// Fails to compile when a member is added to [`Fields`] without being listed
+/// here. Along with the exhaustive `match` of [`Fields::is_set`], this keeps
+/// [`Field`] and [`Fields`] in sync, and a test checks that every variant of
+/// [`Field`] sets the member it is mapped to.
+const _: fn(&Fields) = |fields| {
+    let Fields {
+        extra: _,
+        headline: _,
+        standfirst: _,
+        trail_text: _,
+        byline: _,
+        byline_html: _,
+        main: _,
+        body: _,
+        body_text: _,
+        wordcount: _,
+        char_count: _,
+        star_rating: _,
+        score: _,
+        newspaper_page_number: _,
+        newspaper_edition_date: _,
+        publication: _,
+        production_office: _,
+        first_publication_date: _,
+        last_modified: _,
+        scheduled_publication_date: _,
+        comment_close_date: _,
+        commentable: _,
+        is_premoderated: _,
+        allow_ugc: _,
+        has_story_package: _,
+        show_in_related_content: _,
+        live_blogging_now: _,
+        is_live: _,
+        should_hide_adverts: _,
+        should_hide_reader_revenue: _,
+        show_affiliate_links: _,
+        is_inappropriate_for_sponsorship: _,
+        legally_sensitive: _,
+        sensitive: _,
+        contributor_bio: _,
+        membership_access: _,
+        display_hint: _,
+        short_url: _,
+        thumbnail: _,
+        lang: _,
+        internal_page_code: _,
+        internal_short_id: _,
+        internal_video_code: _,
+        short_social_share_text: _,
+        social_share_text: _,
+    } = fields;
+};
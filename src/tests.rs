@@ -1,10 +1,24 @@
 #[cfg(all(test, not(feature = "blocking")))]
 mod async_client {
-    use reqwest::Url;
+    use futures_util::{StreamExt, TryStreamExt};
+    use reqwest::header::{HeaderValue, ETAG};
+    use reqwest::{StatusCode, Url};
+    use std::sync::Arc;
+    use std::time::Duration;
 
     use crate::{
-        enums::{Endpoint, Field, OrderBy, OrderDate, UseDate},
+        cache::{CacheStore, CachedResponse, DiskCache, ResponseCache},
+        cassette::CassetteTransport,
+        enums::{
+            AtomLookupType, AtomType, ElementType, Endpoint, Field, OrderBy, OrderDate,
+            ReferenceType, ReferenceTypeFilter, RightsFilter, TagType, UseDate,
+        },
         error::Error,
+        helpers::mock_response,
+        quota::QuotaTracker,
+        retry::RetryPolicy,
+        structs::{AtomData, SearchResponse},
+        transport::{HttpResponse, InMemoryTransport},
         GuardianContentClient,
     };
 
@@ -12,6 +26,31 @@ mod async_client {
         GuardianContentClient::new("test-api-key")
     }
 
+    fn search_page(current_page: u32, pages: u32) -> HttpResponse {
+        HttpResponse::json(format!(
+            r#"{{"response": {{
+                "status": "ok",
+                "userTier": "developer",
+                "total": {pages},
+                "currentPage": {current_page},
+                "pages": {pages},
+                "results": [{{
+                    "id": "world/article-{current_page}",
+                    "webTitle": "Article {current_page}",
+                    "webUrl": "https://www.theguardian.com/world/article-{current_page}",
+                    "apiUrl": "https://content.guardianapis.com/world/article-{current_page}"
+                }}]
+            }}}}"#
+        ))
+    }
+
+    fn client_with_transport(transport: &Arc<InMemoryTransport>) -> GuardianContentClient {
+        GuardianContentClient::builder("test-api-key")
+            .transport(Arc::clone(transport))
+            .build()
+            .unwrap()
+    }
+
     #[test]
     fn test_api_key() {
         let client = client_setup();
@@ -119,6 +158,19 @@ mod async_client {
         assert_eq!(client.request.get("show-fields").unwrap(), "all");
     }
 
+    #[test]
+    fn test_show_content_fields() {
+        let client = client_setup().build_request().show_fields(vec![
+            Field::BylineHtml,
+            Field::IsInappropriateForSponsorship,
+            Field::InternalShortId,
+        ]);
+        assert_eq!(
+            client.request.get("show-fields").unwrap(),
+            "bylineHtml,isInappropriateForSponsorship,internalShortId"
+        );
+    }
+
     #[test]
     fn test_show_tags() {
         let client = client_setup().build_request().show_tags(vec![
@@ -326,6 +378,58 @@ mod async_client {
         );
     }
 
+    #[test]
+    fn test_client_builder_defaults() {
+        let client = GuardianContentClient::builder("test-api-key")
+            .build()
+            .unwrap();
+        assert_eq!(client.api_key, "test-api-key");
+        assert_eq!(
+            client.base_url,
+            Url::parse("https://content.guardianapis.com").unwrap()
+        );
+    }
+
+    #[test]
+    fn test_client_builder_base_url() {
+        let client = GuardianContentClient::builder("test-api-key")
+            .base_url("http://localhost:8080/capi/")
+            .timeout(Duration::from_secs(5))
+            .connect_timeout(Duration::from_secs(1))
+            .read_timeout(Duration::from_secs(2))
+            .user_agent("aletheia-tests")
+            .build()
+            .unwrap();
+        assert_eq!(
+            client.base_url,
+            Url::parse("http://localhost:8080/capi/").unwrap()
+        );
+
+        let request = client.build_request();
+        assert_eq!(request.base_url, client.base_url);
+    }
+
+    #[test]
+    fn test_client_builder_invalid_base_url() {
+        let result = GuardianContentClient::builder("test-api-key")
+            .base_url("not a url")
+            .build();
+        assert!(matches!(result, Err(Error::InvalidBaseUrl(_))));
+
+        let result = GuardianContentClient::builder("test-api-key")
+            .base_url("mailto:someone@example.com")
+            .build();
+        assert!(matches!(result, Err(Error::InvalidBaseUrl(_))));
+    }
+
+    #[test]
+    fn test_client_builder_invalid_user_agent() {
+        let result = GuardianContentClient::builder("test-api-key")
+            .user_agent("invalid\nuser agent")
+            .build();
+        assert!(matches!(result, Err(Error::ClientError(_))));
+    }
+
     #[tokio::test]
     async fn test_error_missing_parameter() {
         let result = client_setup()
@@ -339,33 +443,2322 @@ mod async_client {
         let err = result.err().unwrap();
         assert!(matches!(err, Error::MissingQueryParameter("q")));
     }
-}
-
-#[cfg(all(test, feature = "blocking"))]
-mod blocking_client {
 
-    use crate::{enums::Endpoint, error::Error, GuardianContentClient};
+    #[test]
+    fn test_starting_page() {
+        let client = client_setup().build_request();
+        assert_eq!(crate::helpers::starting_page(&client.request), 1);
 
-    fn client_setup() -> GuardianContentClient {
-        GuardianContentClient::new("test-api-key")
+        let client = client_setup().build_request().page(4);
+        assert_eq!(crate::helpers::starting_page(&client.request), 4);
     }
 
     #[test]
-    fn test_api_key() {
-        let client = client_setup();
-        assert_eq!(client.api_key, "test-api-key");
+    fn test_next_page() {
+        let mut response: SearchResponse = serde_json::from_str(
+            r#"{
+                "status": "ok",
+                "currentPage": 1,
+                "pages": 2,
+                "results": [{
+                    "id": "world/2022/jan/01/an-article",
+                    "webTitle": "An article",
+                    "webUrl": "https://www.theguardian.com/world/2022/jan/01/an-article",
+                    "apiUrl": "https://content.guardianapis.com/world/2022/jan/01/an-article"
+                }]
+            }"#,
+        )
+        .unwrap();
+        assert_eq!(crate::helpers::next_page(&response), Some(2));
+
+        response.current_page = Some(2);
+        assert_eq!(crate::helpers::next_page(&response), None);
+
+        response.current_page = Some(1);
+        response.results = Some(vec![]);
+        assert_eq!(crate::helpers::next_page(&response), None);
+
+        assert_eq!(crate::helpers::next_page(&mock_response()), None);
     }
 
-    #[test]
-    fn test_error_missing_parameter() {
-        let result = client_setup()
+    #[tokio::test]
+    async fn test_stream_pages_stops_on_error() {
+        let pages = client_setup()
             .build_request()
             .endpoint(Endpoint::SingleItem)
-            .send();
+            .stream_pages(None)
+            .collect::<Vec<_>>()
+            .await;
 
-        assert!(result.is_err());
+        assert_eq!(pages.len(), 1);
+        assert!(matches!(pages[0], Err(Error::MissingQueryParameter("q"))));
+    }
 
-        let err = result.err().unwrap();
-        assert!(matches!(err, Error::MissingQueryParameter("q")));
+    #[tokio::test]
+    async fn test_stream_pages_limit() {
+        let pages = client_setup()
+            .build_request()
+            .stream_pages(Some(0))
+            .collect::<Vec<_>>()
+            .await;
+
+        assert!(pages.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_send_through_transport() {
+        let transport = Arc::new(InMemoryTransport::new().route("search", search_page(1, 1)));
+        let response = client_with_transport(&transport)
+            .build_request()
+            .search("politics")
+            .page_size(1)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(response.user_tier.as_deref(), Some("developer"));
+        assert_eq!(response.results.unwrap()[0].id, "world/article-1");
+
+        let requests = transport.requests();
+        assert_eq!(requests.len(), 1);
+        assert_eq!(requests[0].path(), "/search");
+        assert!(requests[0]
+            .query_pairs()
+            .any(|(key, value)| key == "q" && value == "politics"));
+    }
+
+    #[tokio::test]
+    async fn test_send_single_item_path() {
+        let transport = Arc::new(InMemoryTransport::new().route(
+            "books/2022/jan/01/2022-in-books",
+            HttpResponse::json(r#"{"response": {"status": "ok"}}"#),
+        ));
+        client_with_transport(&transport)
+            .build_request()
+            .endpoint(Endpoint::SingleItem)
+            .search("books/2022/jan/01/2022-in-books")
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            transport.requests()[0].path(),
+            "/books/2022/jan/01/2022-in-books"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_send_api_error() {
+        let transport = Arc::new(InMemoryTransport::new());
+        let result = client_with_transport(&transport)
+            .build_request()
+            .endpoint(Endpoint::Tags)
+            .send()
+            .await;
+
+        assert!(matches!(result, Err(Error::ApiError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_send_retries() {
+        let transport = Arc::new(
+            InMemoryTransport::new()
+                .route_once(
+                    "search",
+                    HttpResponse::new(StatusCode::SERVICE_UNAVAILABLE, ""),
+                )
+                .route("search", search_page(1, 1)),
+        );
+        let client = GuardianContentClient::builder("test-api-key")
+            .transport(Arc::clone(&transport))
+            .retry_policy(RetryPolicy::default().initial_backoff(Duration::ZERO))
+            .build()
+            .unwrap();
+
+        let response = client.build_request().send().await.unwrap();
+
+        assert_eq!(response.current_page, Some(1));
+        assert_eq!(transport.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_send_cached() {
+        let transport = Arc::new(InMemoryTransport::new().route("search", search_page(1, 1)));
+        let client = GuardianContentClient::builder("test-api-key")
+            .transport(Arc::clone(&transport))
+            .cache(ResponseCache::in_memory())
+            .build()
+            .unwrap();
+
+        for _ in 0..2 {
+            let response = client
+                .build_request()
+                .search("politics")
+                .send()
+                .await
+                .unwrap();
+            assert_eq!(response.results.unwrap()[0].id, "world/article-1");
+        }
+        client.build_request().search("sport").send().await.unwrap();
+
+        assert_eq!(transport.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_send_cached_on_disk() {
+        let dir = tempfile::tempdir().unwrap();
+        let transport = Arc::new(InMemoryTransport::new().route("search", search_page(1, 1)));
+
+        for _ in 0..2 {
+            let client = GuardianContentClient::builder("test-api-key")
+                .transport(Arc::clone(&transport))
+                .cache(ResponseCache::new(DiskCache::new(dir.path())))
+                .build()
+                .unwrap();
+            let response = client.build_request().send().await.unwrap();
+            assert_eq!(response.results.unwrap()[0].id, "world/article-1");
+        }
+
+        assert_eq!(transport.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_send_cached_off_runtime_thread() {
+        #[derive(Debug, Default)]
+        struct ThreadRecordingCache {
+            threads: std::sync::Mutex<Vec<std::thread::ThreadId>>,
+        }
+
+        impl CacheStore for ThreadRecordingCache {
+            fn get(&self, _key: &str) -> Option<CachedResponse> {
+                self.threads
+                    .lock()
+                    .unwrap()
+                    .push(std::thread::current().id());
+                None
+            }
+
+            fn put(&self, _key: &str, _response: CachedResponse) {
+                self.threads
+                    .lock()
+                    .unwrap()
+                    .push(std::thread::current().id());
+            }
+        }
+
+        let store = Arc::new(ThreadRecordingCache::default());
+        let transport = Arc::new(InMemoryTransport::new().route("search", search_page(1, 1)));
+        let client = GuardianContentClient::builder("test-api-key")
+            .transport(Arc::clone(&transport))
+            .cache(ResponseCache::new(Arc::clone(&store)))
+            .build()
+            .unwrap();
+        client.build_request().send().await.unwrap();
+
+        let threads = store.threads.lock().unwrap();
+        assert_eq!(threads.len(), 2);
+        assert!(threads
+            .iter()
+            .all(|thread| *thread != std::thread::current().id()));
+    }
+
+    #[tokio::test]
+    async fn test_send_cached_revalidates() {
+        let transport = Arc::new(
+            InMemoryTransport::new()
+                .route_once(
+                    "search",
+                    search_page(1, 1).with_header(ETAG, HeaderValue::from_static("\"v1\"")),
+                )
+                .route("search", HttpResponse::new(StatusCode::NOT_MODIFIED, "")),
+        );
+        let client = GuardianContentClient::builder("test-api-key")
+            .transport(Arc::clone(&transport))
+            .cache(ResponseCache::in_memory().ttl(Endpoint::Content, Duration::ZERO))
+            .build()
+            .unwrap();
+
+        for _ in 0..2 {
+            let response = client.build_request().send().await.unwrap();
+            assert_eq!(response.results.unwrap()[0].id, "world/article-1");
+        }
+
+        assert_eq!(transport.requests().len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_send_tags() {
+        let transport = Arc::new(InMemoryTransport::new().route(
+            "tags",
+            HttpResponse::json(
+                r#"{"response": {
+                    "status": "ok",
+                    "userTier": "developer",
+                    "total": 1,
+                    "startIndex": 1,
+                    "pageSize": 10,
+                    "currentPage": 1,
+                    "pages": 1,
+                    "results": [{
+                        "id": "profile/marinahyde",
+                        "type": "contributor",
+                        "webTitle": "Marina Hyde",
+                        "webUrl": "https://www.theguardian.com/profile/marinahyde",
+                        "apiUrl": "https://content.guardianapis.com/profile/marinahyde",
+                        "bio": "<p>Marina Hyde is a Guardian columnist</p>",
+                        "twitterHandle": "MarinaHyde",
+                        "firstName": "Marina",
+                        "lastName": "Hyde"
+                    }]
+                }}"#,
+            ),
+        ));
+        let response = client_with_transport(&transport)
+            .tags()
+            .search("hyde")
+            .tag_type(TagType::Contributor)
+            .send()
+            .await
+            .unwrap();
+
+        let tag = &response.results.unwrap()[0];
+        assert_eq!(tag.twitter_handle.as_deref(), Some("MarinaHyde"));
+        assert!(tag.bio.is_some());
+        assert!(tag.references.is_empty());
+        assert_eq!(transport.requests()[0].path(), "/tags");
+    }
+
+    #[tokio::test]
+    async fn test_send_tags_references() {
+        let transport = Arc::new(InMemoryTransport::new().route(
+            "tags",
+            HttpResponse::json(
+                r#"{"response": {
+                    "status": "ok",
+                    "results": [{
+                        "id": "books/wolf-hall",
+                        "type": "keyword",
+                        "webTitle": "Wolf Hall",
+                        "webUrl": "https://www.theguardian.com/books/wolf-hall",
+                        "apiUrl": "https://content.guardianapis.com/books/wolf-hall",
+                        "references": [{"id": "isbn/9780007230181", "type": "isbn"}]
+                    }]
+                }}"#,
+            ),
+        ));
+        let response = client_with_transport(&transport)
+            .tags()
+            .web_title("Wolf Hall")
+            .reference_type(ReferenceTypeFilter::Isbn)
+            .show_references(vec![ReferenceType::Isbn, ReferenceType::MusicbrainzGenre])
+            .send()
+            .await
+            .unwrap();
+
+        let tag = &response.results.unwrap()[0];
+        assert_eq!(tag.references[0].id, "isbn/9780007230181");
+
+        let query = transport.requests()[0]
+            .query_pairs()
+            .into_owned()
+            .collect::<Vec<_>>();
+        assert!(query.contains(&("web-title".to_owned(), "Wolf Hall".to_owned())));
+        assert!(query.contains(&("reference-type".to_owned(), "isbn".to_owned())));
+        assert!(query.contains(&(
+            "show-references".to_owned(),
+            "isbn,musicbrainzgenre".to_owned()
+        )));
+    }
+
+    #[tokio::test]
+    async fn test_send_sections_and_editions() {
+        let transport = Arc::new(
+            InMemoryTransport::new()
+                .route(
+                    "sections",
+                    HttpResponse::json(
+                        r#"{"response": {
+                            "status": "ok",
+                            "total": 1,
+                            "results": [{
+                                "id": "books",
+                                "webTitle": "Books",
+                                "webUrl": "https://www.theguardian.com/books",
+                                "apiUrl": "https://content.guardianapis.com/books",
+                                "editions": [{
+                                    "id": "books",
+                                    "webTitle": "Books",
+                                    "webUrl": "https://www.theguardian.com/books",
+                                    "apiUrl": "https://content.guardianapis.com/books",
+                                    "code": "default"
+                                }]
+                            }]
+                        }}"#,
+                    ),
+                )
+                .route(
+                    "editions",
+                    HttpResponse::json(
+                        r#"{"response": {
+                            "status": "ok",
+                            "total": 1,
+                            "results": [{
+                                "id": "uk",
+                                "path": "uk",
+                                "edition": "UK",
+                                "webTitle": "new guardian uk front page",
+                                "webUrl": "https://www.theguardian.com/uk",
+                                "apiUrl": "https://content.guardianapis.com/uk"
+                            }]
+                        }}"#,
+                    ),
+                ),
+        );
+        let client = client_with_transport(&transport);
+
+        let sections = client.sections().search("books").send().await.unwrap();
+        assert_eq!(sections.results.unwrap()[0].editions[0].code, "default");
+
+        let editions = client.editions().send().await.unwrap();
+        assert_eq!(editions.results.unwrap()[0].edition, "UK");
+    }
+
+    #[tokio::test]
+    async fn test_send_item() {
+        let transport = Arc::new(
+            InMemoryTransport::new()
+                .route(
+                    "books/2022/jan/01/2022-in-books",
+                    HttpResponse::json(
+                        r#"{"response": {
+                            "status": "ok",
+                            "total": 1,
+                            "content": {
+                                "id": "books/2022/jan/01/2022-in-books",
+                                "type": "article",
+                                "sectionId": "books",
+                                "webTitle": "2022 in books",
+                                "webUrl": "https://www.theguardian.com/books/2022/jan/01/2022-in-books",
+                                "apiUrl": "https://content.guardianapis.com/books/2022/jan/01/2022-in-books",
+                                "isHosted": false
+                            }
+                        }}"#,
+                    ),
+                )
+                .route(
+                    "technology/apple",
+                    HttpResponse::json(
+                        r#"{"response": {
+                            "status": "ok",
+                            "total": 2,
+                            "currentPage": 1,
+                            "pages": 1,
+                            "tag": {
+                                "id": "technology/apple",
+                                "type": "keyword",
+                                "webTitle": "Apple",
+                                "webUrl": "https://www.theguardian.com/technology/apple",
+                                "apiUrl": "https://content.guardianapis.com/technology/apple"
+                            },
+                            "results": []
+                        }}"#,
+                    ),
+                ),
+        );
+        let client = client_with_transport(&transport);
+
+        let response = client
+            .item("books/2022/jan/01/2022-in-books")
+            .show_fields(vec![Field::Body])
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.content.unwrap().web_title, "2022 in books");
+
+        let response = client
+            .item("technology/apple")
+            .page_size(5)
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.tag.unwrap().web_title, "Apple");
+        assert!(response.content.is_none());
+
+        let requests = transport.requests();
+        assert_eq!(requests[0].path(), "/books/2022/jan/01/2022-in-books");
+        assert_eq!(requests[0].query(), Some("show-fields=body"));
+        assert_eq!(requests[1].query(), Some("page-size=5"));
+    }
+
+    #[tokio::test]
+    async fn test_send_item_related() {
+        let transport = Arc::new(InMemoryTransport::new().route(
+            "world/2024/jan/01/some-article",
+            HttpResponse::json(
+                r#"{"response": {
+                    "status": "ok",
+                    "content": {
+                        "id": "world/2024/jan/01/some-article",
+                        "type": "article",
+                        "webTitle": "Some article",
+                        "webUrl": "https://www.theguardian.com/world/2024/jan/01/some-article",
+                        "apiUrl": "https://content.guardianapis.com/world/2024/jan/01/some-article",
+                        "isHosted": false
+                    },
+                    "relatedContent": [{
+                        "id": "world/2024/jan/02/related-article",
+                        "webTitle": "Related article",
+                        "webUrl": "https://www.theguardian.com/world/2024/jan/02/related-article",
+                        "apiUrl": "https://content.guardianapis.com/world/2024/jan/02/related-article"
+                    }],
+                    "storyPackage": []
+                }}"#,
+            ),
+        ));
+        let response = client_with_transport(&transport)
+            .item("world/2024/jan/01/some-article")
+            .show_related(true)
+            .show_story_package(true)
+            .send()
+            .await
+            .unwrap();
+
+        assert_eq!(
+            response.related_content.unwrap()[0].id,
+            "world/2024/jan/02/related-article"
+        );
+        assert_eq!(response.story_package.unwrap().len(), 0);
+        assert!(response.editors_picks.is_none());
+
+        let query = transport.requests()[0]
+            .query_pairs()
+            .into_owned()
+            .collect::<Vec<_>>();
+        assert!(query.contains(&("show-related".to_owned(), "true".to_owned())));
+        assert!(query.contains(&("show-story-package".to_owned(), "true".to_owned())));
+    }
+
+    #[tokio::test]
+    async fn test_send_atom() {
+        let transport = Arc::new(InMemoryTransport::new().route(
+            "atom/explainer/0a1b2c3d",
+            HttpResponse::json(
+                r#"{"response": {
+                    "status": "ok",
+                    "total": 1,
+                    "explainer": {
+                        "id": "0a1b2c3d",
+                        "atomType": "explainer",
+                        "labels": [],
+                        "defaultHtml": "<div>What is a filibuster?</div>",
+                        "data": {
+                            "explainer": {
+                                "title": "What is a filibuster?",
+                                "body": "<p>A filibuster is a tactic used to delay a vote.</p>",
+                                "displayType": "expandable"
+                            }
+                        },
+                        "contentChangeDetails": {"revision": 3}
+                    }
+                }}"#,
+            ),
+        ));
+        let response = client_with_transport(&transport)
+            .atom(AtomLookupType::Explainer, "0a1b2c3d")
+            .send()
+            .await
+            .unwrap();
+
+        let atom = response.atom.unwrap();
+        assert_eq!(atom.atom_type, "explainer");
+        let AtomData::Explainer(explainer) = atom.data else {
+            panic!("expected an explainer, got {:?}", atom.data);
+        };
+        assert_eq!(explainer.title, "What is a filibuster?");
+    }
+
+    #[tokio::test]
+    async fn test_send_show_atoms() {
+        let transport = Arc::new(InMemoryTransport::new().route(
+            "politics/2024/jul/05/quiz",
+            HttpResponse::json(
+                r#"{"response": {
+                    "status": "ok",
+                    "content": {
+                        "id": "politics/2024/jul/05/quiz",
+                        "type": "article",
+                        "webTitle": "Quiz",
+                        "webUrl": "https://www.theguardian.com/politics/2024/jul/05/quiz",
+                        "apiUrl": "https://content.guardianapis.com/politics/2024/jul/05/quiz",
+                        "isHosted": false,
+                        "atoms": {
+                            "quizzes": [{
+                                "id": "q1",
+                                "atomType": "quiz",
+                                "data": {
+                                    "quiz": {
+                                        "title": "Election quiz",
+                                        "quizType": "knowledge",
+                                        "content": {
+                                            "questions": [{
+                                                "questionText": "Who won?",
+                                                "assets": [],
+                                                "answers": [{"answerText": "Labour", "weight": 1}]
+                                            }]
+                                        }
+                                    }
+                                }
+                            }],
+                            "explainers": [{
+                                "id": "x1",
+                                "atomType": "commonsdivision",
+                                "data": {"commonsdivision": {"votes": []}}
+                            }]
+                        }
+                    }
+                }}"#,
+            ),
+        ));
+        let response = client_with_transport(&transport)
+            .item("politics/2024/jul/05/quiz")
+            .show_atoms(vec![AtomType::Quiz, AtomType::Explainer])
+            .send()
+            .await
+            .unwrap();
+
+        let atoms = response.content.unwrap().atoms.unwrap();
+        let AtomData::Quiz(quiz) = &atoms.quizzes.unwrap()[0].data else {
+            panic!("expected a quiz");
+        };
+        assert_eq!(quiz.content.questions[0].answers[0].answer_text, "Labour");
+        assert!(matches!(
+            atoms.explainers.unwrap()[0].data,
+            AtomData::Other(_)
+        ));
+        assert_eq!(
+            transport.requests()[0].query(),
+            Some("show-atoms=quiz%2Cexplainer")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_send_elements_and_rights() {
+        let transport = Arc::new(InMemoryTransport::new().route(
+            "world/2024/jan/01/some-article",
+            HttpResponse::json(
+                r#"{"response": {
+                    "status": "ok",
+                    "content": {
+                        "id": "world/2024/jan/01/some-article",
+                        "type": "article",
+                        "webTitle": "Some article",
+                        "webUrl": "https://www.theguardian.com/world/2024/jan/01/some-article",
+                        "apiUrl": "https://content.guardianapis.com/world/2024/jan/01/some-article",
+                        "isHosted": false,
+                        "elements": [{
+                            "id": "gu-image-1",
+                            "relation": "main",
+                            "type": "image",
+                            "assets": [{
+                                "type": "image",
+                                "mimeType": "image/jpeg",
+                                "file": "https://media.guim.co.uk/1/500.jpg",
+                                "typeData": {"width": 500, "height": 300, "caption": "A caption"}
+                            }, {
+                                "type": "image",
+                                "mimeType": "image/jpeg",
+                                "file": "https://media.guim.co.uk/1/1000.jpg",
+                                "typeData": {"width": 1000, "height": 600, "isMaster": true}
+                            }]
+                        }],
+                        "rights": {
+                            "syndicatable": true,
+                            "subscriptionDatabases": false
+                        }
+                    }
+                }}"#,
+            ),
+        ));
+        let response = client_with_transport(&transport)
+            .item("world/2024/jan/01/some-article")
+            .show_elements(vec![ElementType::Image, ElementType::Video])
+            .show_rights(true)
+            .send()
+            .await
+            .unwrap();
+
+        let content = response.content.unwrap();
+        let elements = content.elements.unwrap();
+        assert_eq!(elements[0].relation, "main");
+        assert_eq!(elements[0].assets.len(), 2);
+        assert_eq!(
+            elements[0].assets[1].type_data.as_ref().unwrap().is_master,
+            Some(true)
+        );
+
+        let rights = content.rights.unwrap();
+        assert!(rights.syndicatable);
+        assert!(!rights.subscription_databases);
+        assert!(!rights.developer_community);
+
+        let query = transport.requests()[0]
+            .query_pairs()
+            .into_owned()
+            .collect::<Vec<_>>();
+        assert!(query.contains(&("show-elements".to_owned(), "image,video".to_owned())));
+        assert!(query.contains(&("show-rights".to_owned(), "all".to_owned())));
+    }
+
+    #[tokio::test]
+    async fn test_send_rights_filter() {
+        let transport = Arc::new(InMemoryTransport::new().route(
+            "search?rights=syndicatable",
+            HttpResponse::json(
+                r#"{"response": {
+                    "status": "ok",
+                    "results": [{
+                        "id": "world/article-1",
+                        "webTitle": "Article 1",
+                        "webUrl": "https://www.theguardian.com/world/article-1",
+                        "apiUrl": "https://content.guardianapis.com/world/article-1",
+                        "rights": {"syndicatable": true, "subscriptionDatabases": true}
+                    }]
+                }}"#,
+            ),
+        ));
+        let response = client_with_transport(&transport)
+            .build_request()
+            .rights(RightsFilter::Syndicatable)
+            .show_rights(true)
+            .send()
+            .await
+            .unwrap();
+
+        let rights = response.results.unwrap()[0].rights.clone().unwrap();
+        assert!(rights.allows(RightsFilter::Syndicatable));
+        assert!(rights.allows(RightsFilter::SubscriptionDatabases));
+        assert!(!rights.allows(RightsFilter::DeveloperCommunity));
+    }
+
+    #[tokio::test]
+    async fn test_send_item_from_url() {
+        let transport = Arc::new(InMemoryTransport::new().route(
+            "books/2022/jan/01/2022-in-books",
+            HttpResponse::json(
+                r#"{"response": {
+                    "status": "ok",
+                    "content": {
+                        "id": "books/2022/jan/01/2022-in-books",
+                        "type": "article",
+                        "webTitle": "2022 in books",
+                        "webUrl": "https://www.theguardian.com/books/2022/jan/01/2022-in-books",
+                        "apiUrl": "https://content.guardianapis.com/books/2022/jan/01/2022-in-books",
+                        "isHosted": false
+                    }
+                }}"#,
+            ),
+        ));
+        let client = client_with_transport(&transport);
+
+        let response = client
+            .item_from_url(
+                "https://www.theguardian.com/books/2022/jan/01/2022-in-books?CMP=share_btn_url",
+            )
+            .unwrap()
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(
+            response.content.unwrap().id,
+            "books/2022/jan/01/2022-in-books"
+        );
+
+        let result =
+            client.item_from_url("https://www.example.com/books/2022/jan/01/2022-in-books");
+        assert!(matches!(result, Err(Error::InvalidId(_))));
+        assert_eq!(transport.requests().len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_send_item_api_error() {
+        let transport = Arc::new(InMemoryTransport::new());
+        let result = client_with_transport(&transport)
+            .item("does/not/exist")
+            .send()
+            .await;
+
+        assert!(matches!(result, Err(Error::ApiError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_send_invalid_ids() {
+        let transport = Arc::new(InMemoryTransport::new());
+        let client = client_with_transport(&transport);
+
+        let result = client.item("world/2024/jan/01/some article").send().await;
+        assert!(
+            matches!(result, Err(Error::InvalidId(id)) if id == "world/2024/jan/01/some article")
+        );
+
+        let result = client
+            .build_request()
+            .section("technology/apple")
+            .send()
+            .await;
+        assert!(matches!(result, Err(Error::InvalidId(id)) if id == "technology/apple"));
+
+        assert!(transport.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_persisted_quota_lock_does_not_block_runtime() {
+        use fs4::fs_std::FileExt;
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("quota.json");
+        let quota = QuotaTracker::new(10).persist_to(&path);
+
+        // Another process holding the lock on the counter
+        let lock = std::fs::File::create(dir.path().join("quota.json.lock")).unwrap();
+        lock.lock_exclusive().unwrap();
+
+        let transport = Arc::new(InMemoryTransport::new().route("search", search_page(1, 1)));
+        let client = GuardianContentClient::builder("test-api-key")
+            .transport(Arc::clone(&transport))
+            .quota(quota.clone())
+            .build()
+            .unwrap();
+        let request = tokio::spawn(async move { client.build_request().send().await });
+
+        // The single-threaded runtime keeps running other tasks meanwhile
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert!(!request.is_finished());
+
+        FileExt::unlock(&lock).unwrap();
+        request.await.unwrap().unwrap();
+        assert_eq!(quota.used().unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_send_strict() {
+        let body = r#"{"response": {
+            "status": "ok",
+            "results": [{
+                "id": "world/article",
+                "webTitle": "Article",
+                "webUrl": "https://www.theguardian.com/world/article",
+                "apiUrl": "https://content.guardianapis.com/world/article",
+                "newField": {"added": true}
+            }]
+        }}"#;
+        let transport =
+            Arc::new(InMemoryTransport::new().route("search", HttpResponse::json(body)));
+
+        let response = client_with_transport(&transport)
+            .build_request()
+            .send()
+            .await
+            .unwrap();
+        let result = &response.results.unwrap()[0];
+        assert_eq!(result.extra["newField"]["added"], true);
+
+        let result = GuardianContentClient::builder("test-api-key")
+            .transport(Arc::clone(&transport))
+            .strict(true)
+            .build()
+            .unwrap()
+            .build_request()
+            .send()
+            .await;
+        assert!(
+            matches!(result, Err(Error::JsonError(err)) if err.to_string().contains("newField"))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_send_raw() {
+        let transport = Arc::new(InMemoryTransport::new().route("search", search_page(1, 1)));
+        let client = client_with_transport(&transport);
+
+        let raw = client
+            .build_request()
+            .search("politics")
+            .send_raw()
+            .await
+            .unwrap();
+        let value: serde_json::Value = serde_json::from_str(raw.get()).unwrap();
+        assert_eq!(value["response"]["results"][0]["id"], "world/article-1");
+
+        let (response, body) = client
+            .build_request()
+            .search("politics")
+            .send_with_raw()
+            .await
+            .unwrap();
+        assert_eq!(response.results.unwrap()[0].id, "world/article-1");
+        assert_eq!(body, search_page(1, 1).body);
+
+        let transport = Arc::new(InMemoryTransport::new());
+        let result = client_with_transport(&transport)
+            .build_request()
+            .send_raw()
+            .await;
+        assert!(matches!(result, Err(Error::ApiError(_))));
+    }
+
+    #[tokio::test]
+    async fn test_stream_results() {
+        let transport = Arc::new(
+            InMemoryTransport::new()
+                .route("search?page=1", search_page(1, 3))
+                .route("search?page=2", search_page(2, 3))
+                .route("search?page=3", search_page(3, 3)),
+        );
+        let results = client_with_transport(&transport)
+            .build_request()
+            .search("politics")
+            .stream_results(None)
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+
+        let ids = results.iter().map(|result| result.id.as_str());
+        assert!(ids.eq(["world/article-1", "world/article-2", "world/article-3"]));
+
+        // Filters are kept on every page
+        for request in transport.requests() {
+            assert!(request
+                .query_pairs()
+                .any(|(key, value)| key == "q" && value == "politics"));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_stream_pages_max_pages() {
+        let transport = Arc::new(
+            InMemoryTransport::new()
+                .route("search?page=2", search_page(2, 5))
+                .route("search?page=3", search_page(3, 5)),
+        );
+        let pages = client_with_transport(&transport)
+            .build_request()
+            .page(2)
+            .stream_pages(Some(2))
+            .try_collect::<Vec<_>>()
+            .await
+            .unwrap();
+
+        assert_eq!(pages.len(), 2);
+        assert_eq!(pages[1].current_page, Some(3));
+    }
+
+    #[tokio::test]
+    async fn test_record_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        let transport = Arc::new(InMemoryTransport::new().route(
+            "search",
+            HttpResponse::new(StatusCode::SERVICE_UNAVAILABLE, "unavailable"),
+        ));
+        let recording_transport = CassetteTransport::record(dir.path(), Arc::clone(&transport));
+        let url = Url::parse("https://content.guardianapis.com/search").unwrap();
+
+        let client = GuardianContentClient::builder("test-api-key")
+            .transport(recording_transport)
+            .build()
+            .unwrap();
+        assert!(client.build_request().send().await.is_err());
+        assert!(!CassetteTransport::replay(dir.path())
+            .cassette_path(&url)
+            .exists());
+
+        let client = GuardianContentClient::builder("test-api-key")
+            .transport(
+                CassetteTransport::record(dir.path(), Arc::clone(&transport)).record_errors(true),
+            )
+            .build()
+            .unwrap();
+        assert!(client.build_request().send().await.is_err());
+        assert!(CassetteTransport::replay(dir.path())
+            .cassette_path(&url)
+            .exists());
+    }
+
+    #[tokio::test]
+    async fn test_record_and_replay() {
+        let dir = tempfile::tempdir().unwrap();
+        let transport = Arc::new(InMemoryTransport::new().route("search", search_page(1, 1)));
+
+        let recording_client = GuardianContentClient::builder("test-api-key")
+            .transport(CassetteTransport::record(
+                dir.path(),
+                Arc::clone(&transport),
+            ))
+            .build()
+            .unwrap();
+        recording_client
+            .build_request()
+            .search("politics")
+            .page_size(1)
+            .send()
+            .await
+            .unwrap();
+
+        let replaying_client = GuardianContentClient::builder("test-api-key")
+            .transport(CassetteTransport::replay(dir.path()))
+            .build()
+            .unwrap();
+        let response = replaying_client
+            .build_request()
+            .page_size(1)
+            .search("politics")
+            .send()
+            .await
+            .unwrap();
+        assert_eq!(response.results.unwrap()[0].id, "world/article-1");
+        assert_eq!(transport.requests().len(), 1);
+
+        let result = replaying_client
+            .build_request()
+            .search("football")
+            .send()
+            .await;
+        assert!(matches!(result, Err(Error::MissingCassette(_))));
+    }
+}
+
+#[cfg(all(test, feature = "blocking"))]
+mod blocking_client {
+    use reqwest::StatusCode;
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    use crate::{
+        cache::ResponseCache,
+        cassette::CassetteTransport,
+        enums::Endpoint,
+        error::Error,
+        retry::RetryPolicy,
+        transport::{HttpResponse, InMemoryTransport},
+        GuardianContentClient,
+    };
+
+    fn client_setup() -> GuardianContentClient {
+        GuardianContentClient::new("test-api-key")
+    }
+
+    fn search_page(current_page: u32, pages: u32) -> HttpResponse {
+        HttpResponse::json(format!(
+            r#"{{"response": {{
+                "status": "ok",
+                "userTier": "developer",
+                "total": {pages},
+                "currentPage": {current_page},
+                "pages": {pages},
+                "results": [{{
+                    "id": "world/article-{current_page}",
+                    "webTitle": "Article {current_page}",
+                    "webUrl": "https://www.theguardian.com/world/article-{current_page}",
+                    "apiUrl": "https://content.guardianapis.com/world/article-{current_page}"
+                }}]
+            }}}}"#
+        ))
+    }
+
+    fn client_with_transport(transport: &Arc<InMemoryTransport>) -> GuardianContentClient {
+        GuardianContentClient::builder("test-api-key")
+            .transport(Arc::clone(transport))
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_api_key() {
+        let client = client_setup();
+        assert_eq!(client.api_key, "test-api-key");
+    }
+
+    #[test]
+    fn test_client_builder() {
+        let client = GuardianContentClient::builder("test-api-key")
+            .base_url("http://localhost:8080")
+            .timeout(Duration::from_secs(5))
+            .build()
+            .unwrap();
+        assert_eq!(client.base_url.as_str(), "http://localhost:8080/");
+
+        let result = GuardianContentClient::builder("test-api-key")
+            .base_url("not a url")
+            .build();
+        assert!(matches!(result, Err(Error::InvalidBaseUrl(_))));
+    }
+
+    #[test]
+    fn test_error_missing_parameter() {
+        let result = client_setup()
+            .build_request()
+            .endpoint(Endpoint::SingleItem)
+            .send();
+
+        assert!(result.is_err());
+
+        let err = result.err().unwrap();
+        assert!(matches!(err, Error::MissingQueryParameter("q")));
+    }
+
+    #[test]
+    fn test_iter_pages_stops_on_error() {
+        let pages = client_setup()
+            .build_request()
+            .endpoint(Endpoint::SingleItem)
+            .iter_pages(None)
+            .collect::<Vec<_>>();
+
+        assert_eq!(pages.len(), 1);
+        assert!(matches!(pages[0], Err(Error::MissingQueryParameter("q"))));
+    }
+
+    #[test]
+    fn test_iter_results_stops_on_error() {
+        let mut results = client_setup()
+            .build_request()
+            .endpoint(Endpoint::SingleItem)
+            .iter_results(None);
+
+        assert!(matches!(
+            results.next(),
+            Some(Err(Error::MissingQueryParameter("q")))
+        ));
+        assert!(results.next().is_none());
+    }
+
+    #[test]
+    fn test_iter_pages_limit() {
+        let mut pages = client_setup().build_request().iter_pages(Some(0));
+        assert!(pages.next().is_none());
+    }
+
+    #[test]
+    fn test_send_through_transport() {
+        let transport = Arc::new(InMemoryTransport::new().route("search", search_page(1, 1)));
+        let response = client_with_transport(&transport)
+            .build_request()
+            .search("politics")
+            .send()
+            .unwrap();
+
+        assert_eq!(response.results.unwrap()[0].id, "world/article-1");
+        assert_eq!(transport.requests()[0].path(), "/search");
+    }
+
+    #[test]
+    fn test_send_retries() {
+        let transport = Arc::new(
+            InMemoryTransport::new()
+                .route_once(
+                    "search",
+                    HttpResponse::new(StatusCode::TOO_MANY_REQUESTS, ""),
+                )
+                .route("search", search_page(1, 1)),
+        );
+        let client = GuardianContentClient::builder("test-api-key")
+            .transport(Arc::clone(&transport))
+            .retry_policy(RetryPolicy::default().initial_backoff(Duration::ZERO))
+            .build()
+            .unwrap();
+
+        assert!(client.build_request().send().is_ok());
+        assert_eq!(transport.requests().len(), 2);
+    }
+
+    #[test]
+    fn test_send_raw() {
+        let transport = Arc::new(InMemoryTransport::new().route("search", search_page(1, 1)));
+        let client = client_with_transport(&transport);
+
+        let raw = client
+            .build_request()
+            .search("politics")
+            .send_raw()
+            .unwrap();
+        let value: serde_json::Value = serde_json::from_str(raw.get()).unwrap();
+        assert_eq!(value["response"]["results"][0]["id"], "world/article-1");
+
+        let (response, body) = client
+            .build_request()
+            .search("politics")
+            .send_with_raw()
+            .unwrap();
+        assert_eq!(response.results.unwrap()[0].id, "world/article-1");
+        assert_eq!(body, search_page(1, 1).body);
+    }
+
+    #[test]
+    fn test_send_item() {
+        let transport = Arc::new(InMemoryTransport::new().route(
+            "world/series/the-long-read",
+            HttpResponse::json(
+                r#"{"response": {
+                    "status": "ok",
+                    "section": {
+                        "id": "world",
+                        "webTitle": "World news",
+                        "webUrl": "https://www.theguardian.com/world",
+                        "apiUrl": "https://content.guardianapis.com/world",
+                        "editions": []
+                    },
+                    "results": []
+                }}"#,
+            ),
+        ));
+        let response = client_with_transport(&transport)
+            .item("world/series/the-long-read")
+            .send()
+            .unwrap();
+
+        assert_eq!(response.section.unwrap().id, "world");
+        assert_eq!(response.results.unwrap().len(), 0);
+    }
+
+    #[test]
+    fn test_send_cached() {
+        let transport = Arc::new(InMemoryTransport::new().route("tags", search_page(1, 1)));
+        let client = GuardianContentClient::builder("test-api-key")
+            .transport(Arc::clone(&transport))
+            .cache(ResponseCache::in_memory())
+            .build()
+            .unwrap();
+
+        for _ in 0..2 {
+            assert!(client
+                .build_request()
+                .endpoint(Endpoint::Tags)
+                .send()
+                .is_ok());
+        }
+
+        assert_eq!(transport.requests().len(), 1);
+    }
+
+    #[test]
+    fn test_iter_results() {
+        let transport = Arc::new(
+            InMemoryTransport::new()
+                .route("search?page=1", search_page(1, 2))
+                .route("search?page=2", search_page(2, 2)),
+        );
+        let results = client_with_transport(&transport)
+            .build_request()
+            .search("politics")
+            .iter_results(None)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap();
+
+        let ids = results.iter().map(|result| result.id.as_str());
+        assert!(ids.eq(["world/article-1", "world/article-2"]));
+        assert_eq!(transport.requests().len(), 2);
+    }
+
+    #[test]
+    fn test_record_and_replay() {
+        let dir = tempfile::tempdir().unwrap();
+        let transport = Arc::new(InMemoryTransport::new().route("search", search_page(1, 1)));
+
+        let client = GuardianContentClient::builder("test-api-key")
+            .transport(CassetteTransport::replay_or_record(
+                dir.path(),
+                Arc::clone(&transport),
+            ))
+            .build()
+            .unwrap();
+
+        for _ in 0..2 {
+            let response = client.build_request().search("politics").send().unwrap();
+            assert_eq!(response.results.unwrap()[0].id, "world/article-1");
+        }
+        assert_eq!(transport.requests().len(), 1);
+    }
+}
+
+#[cfg(test)]
+mod retry_policy {
+    use reqwest::header::{HeaderMap, HeaderValue, RETRY_AFTER};
+    use reqwest::StatusCode;
+    use std::time::Duration;
+
+    use crate::retry::{Outcome, RetryPolicy};
+
+    fn policy_setup() -> RetryPolicy {
+        RetryPolicy::default()
+            .max_attempts(4)
+            .initial_backoff(Duration::from_millis(100))
+            .max_backoff(Duration::from_millis(300))
+            .max_elapsed(None)
+            .jitter(false)
+    }
+
+    #[test]
+    fn test_backoff() {
+        let policy = policy_setup();
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(300));
+        assert_eq!(policy.backoff(40), Duration::from_millis(300));
+    }
+
+    #[test]
+    fn test_backoff_jitter() {
+        let policy = policy_setup().jitter(true);
+        for _ in 0..100 {
+            let backoff = policy.backoff(2);
+            assert!(backoff >= Duration::from_millis(100));
+            assert!(backoff <= Duration::from_millis(200));
+        }
+    }
+
+    #[test]
+    fn test_retryable_statuses() {
+        let policy = policy_setup();
+        let headers = HeaderMap::new();
+
+        for status in [
+            StatusCode::TOO_MANY_REQUESTS,
+            StatusCode::INTERNAL_SERVER_ERROR,
+            StatusCode::BAD_GATEWAY,
+            StatusCode::SERVICE_UNAVAILABLE,
+        ] {
+            assert_eq!(
+                policy.retry_delay(1, Duration::ZERO, Outcome::Response(status, &headers)),
+                Some(Duration::from_millis(100))
+            );
+        }
+
+        for status in [
+            StatusCode::OK,
+            StatusCode::BAD_REQUEST,
+            StatusCode::UNAUTHORIZED,
+            StatusCode::NOT_FOUND,
+        ] {
+            assert_eq!(
+                policy.retry_delay(1, Duration::ZERO, Outcome::Response(status, &headers)),
+                None
+            );
+        }
+    }
+
+    #[test]
+    fn test_max_attempts() {
+        let policy = policy_setup();
+        let headers = HeaderMap::new();
+        let outcome = || Outcome::Response(StatusCode::SERVICE_UNAVAILABLE, &headers);
+
+        assert!(policy.retry_delay(3, Duration::ZERO, outcome()).is_some());
+        assert!(policy.retry_delay(4, Duration::ZERO, outcome()).is_none());
+        assert!(RetryPolicy::none()
+            .retry_delay(1, Duration::ZERO, outcome())
+            .is_none());
+    }
+
+    #[test]
+    fn test_max_elapsed() {
+        let policy = policy_setup().max_elapsed(Some(Duration::from_secs(1)));
+        let headers = HeaderMap::new();
+        let outcome = || Outcome::Response(StatusCode::SERVICE_UNAVAILABLE, &headers);
+
+        assert!(policy
+            .retry_delay(1, Duration::from_millis(800), outcome())
+            .is_some());
+        assert!(policy
+            .retry_delay(1, Duration::from_millis(950), outcome())
+            .is_none());
+    }
+
+    #[test]
+    fn test_retry_after() {
+        let policy = policy_setup();
+        let mut headers = HeaderMap::new();
+        headers.insert(RETRY_AFTER, HeaderValue::from_static("7"));
+
+        assert_eq!(
+            policy.retry_delay(
+                1,
+                Duration::ZERO,
+                Outcome::Response(StatusCode::TOO_MANY_REQUESTS, &headers)
+            ),
+            Some(Duration::from_secs(7))
+        );
+
+        headers.insert(
+            RETRY_AFTER,
+            HeaderValue::from_static("Wed, 21 Oct 2015 07:28:00 GMT"),
+        );
+        assert_eq!(
+            policy.retry_delay(
+                1,
+                Duration::ZERO,
+                Outcome::Response(StatusCode::SERVICE_UNAVAILABLE, &headers)
+            ),
+            Some(Duration::ZERO)
+        );
+    }
+}
+
+#[cfg(test)]
+mod rate_limiting {
+    use std::time::Duration;
+
+    use crate::rate_limit::{RateLimit, RateLimiter};
+    use crate::GuardianContentClient;
+
+    #[test]
+    fn test_burst() {
+        let limiter = RateLimiter::new(RateLimit::new(2, Duration::from_secs(60)));
+        assert!(limiter.try_acquire().is_ok());
+        assert!(limiter.try_acquire().is_ok());
+
+        let wait = limiter.try_acquire().unwrap_err();
+        assert!(wait > Duration::from_secs(29));
+        assert!(wait <= Duration::from_secs(30));
+    }
+
+    #[test]
+    fn test_refill() {
+        let limiter = RateLimiter::new(RateLimit::new(1, Duration::from_millis(20)));
+        assert!(limiter.try_acquire().is_ok());
+        assert!(limiter.try_acquire().is_err());
+
+        std::thread::sleep(Duration::from_millis(25));
+        assert!(limiter.try_acquire().is_ok());
+    }
+
+    #[test]
+    fn test_tier_presets() {
+        assert_eq!(
+            RateLimit::for_tier("developer"),
+            Some(RateLimit::new(1, Duration::from_secs(1)))
+        );
+        assert_eq!(RateLimit::for_tier("rights-managed"), None);
+        assert_eq!(RateLimit::for_tier("internal"), None);
+        assert_eq!(RateLimit::for_tier("partner"), None);
+    }
+
+    #[test]
+    fn test_shared_between_clones() {
+        let client = GuardianContentClient::builder("test-api-key")
+            .rate_limit(RateLimit::new(1, Duration::from_secs(60)))
+            .build()
+            .unwrap();
+        let cloned_client = client.clone();
+
+        let first = client.build_request();
+        let second = cloned_client.build_request();
+
+        assert!(first.rate_limiter.unwrap().try_acquire().is_ok());
+        assert!(second.rate_limiter.unwrap().try_acquire().is_err());
+    }
+
+    #[test]
+    fn test_not_limited_by_default() {
+        let client = GuardianContentClient::new("test-api-key");
+        assert!(client.build_request().rate_limiter.is_none());
+    }
+}
+
+#[cfg(test)]
+mod quota_tracking {
+    use chrono::{Days, Utc};
+
+    use crate::error::Error;
+    use crate::quota::QuotaTracker;
+    use crate::GuardianContentClient;
+
+    #[test]
+    fn test_quota_exhausted() {
+        let quota = QuotaTracker::new(2);
+        assert_eq!(quota.remaining().unwrap(), 2);
+
+        quota.try_acquire().unwrap();
+        quota.try_acquire().unwrap();
+        assert_eq!(quota.used().unwrap(), 2);
+        assert_eq!(quota.remaining().unwrap(), 0);
+
+        assert!(matches!(quota.try_acquire(), Err(Error::QuotaExhausted(2))));
+        assert_eq!(quota.used().unwrap(), 2);
+    }
+
+    #[test]
+    fn test_shared_between_clones() {
+        let quota = QuotaTracker::new(10);
+        let client = GuardianContentClient::builder("test-api-key")
+            .quota(quota.clone())
+            .build()
+            .unwrap();
+
+        client.build_request().quota.unwrap().try_acquire().unwrap();
+        client
+            .clone()
+            .build_request()
+            .quota
+            .unwrap()
+            .try_acquire()
+            .unwrap();
+
+        assert_eq!(quota.remaining().unwrap(), 8);
+        assert_eq!(client.quota().unwrap().remaining().unwrap(), 8);
+    }
+
+    #[test]
+    fn test_persisted_between_trackers() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("quota.json");
+
+        let first = QuotaTracker::new(3).persist_to(&path);
+        let second = QuotaTracker::new(3).persist_to(&path);
+        assert_eq!(first.remaining().unwrap(), 3);
+
+        first.try_acquire().unwrap();
+        second.try_acquire().unwrap();
+        first.try_acquire().unwrap();
+
+        assert_eq!(second.used().unwrap(), 3);
+        assert!(matches!(
+            second.try_acquire(),
+            Err(Error::QuotaExhausted(3))
+        ));
+    }
+
+    #[test]
+    fn test_persisted_concurrently() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("quota.json");
+
+        // Separate trackers only share the file, like separate processes
+        let threads: Vec<_> = (0..8)
+            .map(|_| {
+                let quota = QuotaTracker::new(1000).persist_to(&path);
+                std::thread::spawn(move || {
+                    for _ in 0..25 {
+                        quota.try_acquire().unwrap();
+                    }
+                })
+            })
+            .collect();
+        for thread in threads {
+            thread.join().unwrap();
+        }
+
+        let quota = QuotaTracker::new(1000).persist_to(&path);
+        assert_eq!(quota.used().unwrap(), 200);
+
+        let files = std::fs::read_dir(dir.path()).unwrap().count();
+        assert_eq!(files, 2, "only the counter and its lock file remain");
+    }
+
+    #[test]
+    fn test_persisted_counter_resets_daily() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("quota.json");
+        let yesterday = Utc::now().date_naive() - Days::new(1);
+        std::fs::write(&path, format!(r#"{{"date":"{yesterday}","count":500}}"#)).unwrap();
+
+        let quota = QuotaTracker::developer().persist_to(&path);
+        assert_eq!(quota.remaining().unwrap(), 500);
+        quota.try_acquire().unwrap();
+        assert_eq!(quota.used().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_persisted_counter_invalid() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("quota.json");
+        std::fs::write(&path, "not json").unwrap();
+
+        let quota = QuotaTracker::developer().persist_to(&path);
+        assert!(matches!(quota.try_acquire(), Err(Error::IoError(_))));
+    }
+}
+
+#[cfg(test)]
+mod cassettes {
+    use reqwest::header::{HeaderValue, ETAG, SET_COOKIE};
+    use reqwest::{StatusCode, Url};
+
+    use crate::cassette::CassetteTransport;
+    use crate::helpers::{fingerprint, normalized_url};
+    use crate::transport::HttpResponse;
+
+    #[test]
+    fn test_normalized_url() {
+        let url = Url::parse(
+            "https://content.guardianapis.com/search?q=politics&api-key=secret&page=2#results",
+        )
+        .unwrap();
+        assert_eq!(
+            normalized_url(&url),
+            "https://content.guardianapis.com/search?api-key=REDACTED&page=2&q=politics"
+        );
+    }
+
+    #[test]
+    fn test_fingerprint() {
+        assert_eq!(fingerprint(""), "cbf29ce484222325");
+        assert_eq!(fingerprint("a"), "af63dc4c8601ec8c");
+    }
+
+    #[test]
+    fn test_cassette_redacts_api_key() {
+        let dir = tempfile::tempdir().unwrap();
+        let transport = CassetteTransport::replay(dir.path());
+        let url =
+            Url::parse("https://content.guardianapis.com/tags?api-key=secret&q=food").unwrap();
+
+        let path = transport.cassette_path(&url);
+        assert!(path
+            .file_name()
+            .unwrap()
+            .to_str()
+            .unwrap()
+            .starts_with("tags-"));
+
+        transport
+            .save(&url, &HttpResponse::new(StatusCode::BAD_GATEWAY, "<html>"))
+            .unwrap();
+        let contents = std::fs::read_to_string(&path).unwrap();
+        assert!(!contents.contains("secret"));
+
+        let response = transport.load(&url).unwrap().unwrap();
+        assert_eq!(response.status, StatusCode::BAD_GATEWAY);
+        assert_eq!(response.body, b"<html>");
+    }
+
+    #[test]
+    fn test_cassette_headers() {
+        let dir = tempfile::tempdir().unwrap();
+        let transport = CassetteTransport::replay(dir.path());
+        let url = Url::parse("https://content.guardianapis.com/search?q=food").unwrap();
+
+        let response = HttpResponse::json(r#"{"response": {"status": "ok"}}"#)
+            .with_header(ETAG, HeaderValue::from_static("\"abc\""))
+            .with_header(SET_COOKIE, HeaderValue::from_static("session=secret"));
+        transport.save(&url, &response).unwrap();
+
+        let contents = std::fs::read_to_string(transport.cassette_path(&url)).unwrap();
+        assert!(!contents.contains("secret"));
+
+        let response = transport.load(&url).unwrap().unwrap();
+        assert_eq!(response.headers[ETAG], "\"abc\"");
+        assert!(!response.headers.contains_key(SET_COOKIE));
+    }
+}
+
+#[cfg(test)]
+mod response_cache {
+    use std::time::{Duration, SystemTime};
+
+    use crate::cache::{CacheStore, CachedResponse, DiskCache, MemoryCache};
+
+    fn entry(age: u64) -> CachedResponse {
+        CachedResponse {
+            body: b"{}".to_vec(),
+            etag: None,
+            stored_at: SystemTime::now() - Duration::from_secs(age),
+        }
+    }
+
+    #[test]
+    fn test_is_fresh() {
+        assert!(entry(10).is_fresh(Duration::from_secs(60)));
+        assert!(!entry(60).is_fresh(Duration::from_secs(60)));
+        assert!(!entry(0).is_fresh(Duration::ZERO));
+    }
+
+    #[test]
+    fn test_max_entries() {
+        let cache = MemoryCache::new().max_entries(2);
+        cache.put("a", entry(30));
+        cache.put("b", entry(10));
+        cache.put("a", entry(0));
+        assert_eq!(cache.len(), 2);
+
+        cache.put("c", entry(0));
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get("b").is_none());
+        assert!(cache.get("a").is_some());
+
+        cache.clear();
+        assert!(cache.is_empty());
+    }
+
+    #[test]
+    fn test_disk_cache_persisted() {
+        let dir = tempfile::tempdir().unwrap();
+        let response = CachedResponse {
+            etag: Some("\"v1\"".to_owned()),
+            ..entry(10)
+        };
+
+        DiskCache::new(dir.path().join("cache")).put("a", response.clone());
+        let cache = DiskCache::new(dir.path().join("cache"));
+        assert_eq!(cache.get("a"), Some(response));
+        assert!(cache.get("b").is_none());
+
+        cache.clear().unwrap();
+        assert!(cache.get("a").is_none());
+    }
+
+    #[test]
+    fn test_disk_cache_invalid() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = DiskCache::new(dir.path());
+
+        std::fs::write(cache.entry_path("a"), "not json").unwrap();
+        assert!(cache.get("a").is_none());
+
+        cache.put(
+            "b",
+            CachedResponse {
+                body: b"<html>".to_vec(),
+                ..entry(0)
+            },
+        );
+        assert!(cache.get("b").is_none());
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+    }
+}
+
+#[cfg(test)]
+mod deserialization {
+    use chrono::{TimeZone, Utc};
+    use serde_json::json;
+
+    use crate::strict::{Strict, UnknownMembers};
+    use crate::structs::{
+        AssetFields, AtomData, Block, BlockElement, Content, ElementData, Fields,
+    };
+
+    #[test]
+    fn test_fields_from_strings() {
+        let fields: Fields = serde_json::from_value(json!({
+            "headline": "Headline",
+            "wordcount": "842",
+            "starRating": "4",
+            "score": "12.5",
+            "commentable": "true",
+            "isPremoderated": "false",
+            "lastModified": "2024-03-01T09:30:00Z",
+            "charCount": ""
+        }))
+        .unwrap();
+
+        assert_eq!(fields.headline.as_deref(), Some("Headline"));
+        assert_eq!(fields.wordcount, Some(842));
+        assert_eq!(fields.star_rating, Some(4));
+        assert_eq!(fields.score, Some(12.5));
+        assert_eq!(fields.commentable, Some(true));
+        assert_eq!(fields.is_premoderated, Some(false));
+        assert_eq!(
+            fields.last_modified,
+            Some(Utc.with_ymd_and_hms(2024, 3, 1, 9, 30, 0).unwrap())
+        );
+        assert_eq!(fields.char_count, None);
+        assert_eq!(fields.sensitive, None);
+    }
+
+    #[test]
+    fn test_fields_match_field_variants() {
+        use crate::enums::Field;
+        use strum::IntoEnumIterator;
+
+        // Every member accepts one of these values
+        let values = [json!("true"), json!("1"), json!("2024-03-01T09:30:00Z")];
+
+        for field in Field::iter().filter(|field| *field != Field::All) {
+            let matched = values.iter().any(|value| {
+                serde_json::from_value::<Fields>(json!({ field.to_string(): value }))
+                    .is_ok_and(|fields| fields.extra.is_empty() && fields.is_set(&field))
+            });
+            assert!(matched, "{field} does not set its member");
+        }
+    }
+
+    #[test]
+    fn test_fields_from_native_values() {
+        let content: Content = serde_json::from_value(json!({
+            "id": "world/2024/mar/01/article",
+            "type": "article",
+            "webTitle": "Article",
+            "webUrl": "https://www.theguardian.com/world/2024/mar/01/article",
+            "apiUrl": "https://content.guardianapis.com/world/2024/mar/01/article",
+            "isHosted": false,
+            "fields": {
+                "wordcount": 842,
+                "starRating": 4,
+                "commentable": true,
+                "isPremoderated": false,
+                "lastModified": {
+                    "dateTime": 1709285400000_i64,
+                    "iso8601": "2024-03-01T09:30:00Z"
+                },
+                "firstPublicationDate": { "dateTime": 1709285400000_i64 },
+                "commentCloseDate": null
+            }
+        }))
+        .unwrap();
+
+        let fields = content.fields.unwrap();
+        let date = Utc.with_ymd_and_hms(2024, 3, 1, 9, 30, 0).unwrap();
+        assert_eq!(fields.wordcount, Some(842));
+        assert_eq!(fields.star_rating, Some(4));
+        assert_eq!(fields.commentable, Some(true));
+        assert_eq!(fields.is_premoderated, Some(false));
+        assert_eq!(fields.last_modified, Some(date));
+        assert_eq!(fields.first_publication_date, Some(date));
+        assert_eq!(fields.comment_close_date, None);
+    }
+
+    #[test]
+    fn test_fields_invalid() {
+        let result = serde_json::from_value::<Fields>(json!({ "wordcount": "many" }));
+        assert!(result.is_err());
+
+        let result = serde_json::from_value::<Fields>(json!({ "commentable": "yes" }));
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn test_block_dates() {
+        let block: Block = serde_json::from_value(json!({
+            "id": "65e1a1b08f08e1a2b3c4d5e6",
+            "bodyHtml": "<p>Update</p>",
+            "bodyTextSummary": "Update",
+            "attributes": {},
+            "published": true,
+            "createdDate": "2024-03-01T09:30:00Z",
+            "firstPublishedDate": { "dateTime": 1709285400000_i64, "iso8601": "2024-03-01T09:30:00Z" },
+            "publishedDate": { "dateTime": 1709285460000_i64 },
+            "contributors": [],
+            "elements": []
+        }))
+        .unwrap();
+
+        let date = Utc.with_ymd_and_hms(2024, 3, 1, 9, 30, 0).unwrap();
+        assert_eq!(block.created_date, Some(date));
+        assert_eq!(block.first_published_date, Some(date));
+        assert_eq!(
+            block.published_date,
+            Some(Utc.with_ymd_and_hms(2024, 3, 1, 9, 31, 0).unwrap())
+        );
+        assert_eq!(block.last_modified_date, None);
+    }
+
+    #[test]
+    fn test_asset_dates() {
+        let fields: AssetFields = serde_json::from_value(json!({
+            "dateCreated": { "dateTime": 1709285400000_i64, "iso8601": "2024-03-01T09:30:00Z" },
+            "start": "2024-03-01T09:30:00Z"
+        }))
+        .unwrap();
+
+        let date = Utc.with_ymd_and_hms(2024, 3, 1, 9, 30, 0).unwrap();
+        assert_eq!(fields.date_created, Some(date));
+        assert_eq!(fields.start, Some(date));
+        assert_eq!(fields.end, None);
+    }
+
+    #[test]
+    fn test_block_elements() {
+        let elements: Vec<BlockElement> = serde_json::from_value(json!([
+            {
+                "type": "text",
+                "assets": [],
+                "textTypeData": { "html": "<p>Update</p>" }
+            },
+            {
+                "type": "rich-link",
+                "assets": [],
+                "richLinkTypeData": {
+                    "url": "https://www.theguardian.com/world",
+                    "originalUrl": "https://www.theguardian.com/world",
+                    "linkText": "World news",
+                    "linkPrefix": "Related: "
+                }
+            },
+            {
+                "type": "callout",
+                "assets": [],
+                "calloutTypeData": { "campaignId": "1234" }
+            }
+        ]))
+        .unwrap();
+
+        let ElementData::Text(text) = &elements[0].data else {
+            panic!("expected a text element, got {:?}", elements[0].data);
+        };
+        assert_eq!(text.html.as_deref(), Some("<p>Update</p>"));
+
+        assert!(matches!(elements[1].data, ElementData::RichLink(_)));
+
+        let ElementData::Unknown(value) = &elements[2].data else {
+            panic!("expected an unknown element, got {:?}", elements[2].data);
+        };
+        assert_eq!(elements[2].r#type, "callout");
+        assert_eq!(value["campaignId"], "1234");
+    }
+
+    #[test]
+    fn test_extra_members() {
+        let block: Block = serde_json::from_value(json!({
+            "id": "65e1a1b08f08e1a2b3c4d5e6",
+            "bodyHtml": "<p>Update</p>",
+            "bodyTextSummary": "Update",
+            "attributes": {},
+            "published": true,
+            "contributors": [],
+            "pinnedAt": "2024-03-01T09:30:00Z",
+            "elements": [{
+                "type": "text",
+                "assets": [],
+                "textTypeData": { "html": "<p>Update</p>" },
+                "displayCredit": false
+            }]
+        }))
+        .unwrap();
+
+        assert_eq!(block.extra["pinnedAt"], "2024-03-01T09:30:00Z");
+        assert_eq!(block.elements[0].extra["displayCredit"], false);
+
+        let fields: Fields =
+            serde_json::from_value(json!({ "wordcount": "842", "newField": 1 })).unwrap();
+        assert_eq!(fields.wordcount, Some(842));
+        assert_eq!(fields.extra.len(), 1);
+    }
+
+    #[test]
+    fn test_extra_members_strict() {
+        let element = json!({
+            "type": "callout",
+            "assets": [],
+            "calloutTypeData": { "campaignId": "1234" }
+        });
+        let result = serde_json::from_value::<Strict<BlockElement>>(element);
+        assert!(result.is_ok());
+
+        let element = json!({
+            "type": "text",
+            "assets": [],
+            "textTypeData": { "html": "<p>Update</p>" },
+            "displayCredit": false
+        });
+        let result = serde_json::from_value::<Strict<BlockElement>>(element);
+        assert!(result.unwrap_err().to_string().contains("displayCredit"));
+
+        let fields = json!({ "newField": 1 });
+        let result = serde_json::from_value::<Strict<Fields>>(fields);
+        assert!(result.is_err());
+
+        let content = json!({
+            "id": "world/2024/mar/01/article",
+            "type": "article",
+            "webTitle": "Article",
+            "webUrl": "https://www.theguardian.com/world/2024/mar/01/article",
+            "apiUrl": "https://content.guardianapis.com/world/2024/mar/01/article",
+            "isHosted": false,
+            "blocks": {
+                "body": [{
+                    "id": "block-1",
+                    "bodyHtml": "<p>Update</p>",
+                    "bodyTextSummary": "Update",
+                    "attributes": {},
+                    "published": true,
+                    "contributors": [],
+                    "elements": [],
+                    "pinnedAt": "2024-03-01T09:30:00Z"
+                }]
+            }
+        });
+        let result = serde_json::from_value::<Strict<Content>>(content.clone());
+        assert!(result.unwrap_err().to_string().contains("pinnedAt"));
+        let content = serde_json::from_value::<Content>(content).unwrap();
+        assert!(content.unknown_member().is_some());
+    }
+    #[test]
+    fn test_atom_data() {
+        let data: AtomData = serde_json::from_value(json!({
+            "explainer": {
+                "title": "What is a filibuster?",
+                "body": "<p>A filibuster is a tactic used to delay a vote.</p>",
+                "displayType": "expandable"
+            }
+        }))
+        .unwrap();
+        assert!(matches!(data, AtomData::Explainer(_)));
+
+        let data: AtomData =
+            serde_json::from_value(json!({ "commonsdivision": { "votes": [] } })).unwrap();
+        assert!(matches!(data, AtomData::Other(_)));
+
+        let result = serde_json::from_value::<AtomData>(json!({
+            "quiz": { "title": "Election quiz", "content": { "questions": {} } }
+        }));
+        assert!(result.is_err());
+
+        let result = serde_json::from_value::<AtomData>(json!({
+            "explainer": { "title": "What is a filibuster?" }
+        }));
+        assert!(result.unwrap_err().to_string().contains("body"));
+    }
+}
+
+#[cfg(all(test, feature = "serde-serialize"))]
+mod serialization {
+    use serde_json::{json, Value};
+
+    use crate::structs::{CapiDateTime, Content, SearchResponse};
+
+    fn fixture() -> Value {
+        json!({
+            "status": "ok",
+            "userTier": "developer",
+            "total": 1,
+            "results": [{
+                "id": "world/live/2024/mar/01/live",
+                "type": "liveblog",
+                "sectionId": "world",
+                "webPublicationDate": "2024-03-01T09:30:00Z",
+                "webTitle": "Live",
+                "webUrl": "https://www.theguardian.com/world/live/2024/mar/01/live",
+                "apiUrl": "https://content.guardianapis.com/world/live/2024/mar/01/live",
+                "isHosted": false,
+                "newField": "kept",
+                "fields": {
+                    "headline": "Live",
+                    "wordcount": "842",
+                    "liveBloggingNow": "true"
+                },
+                "tags": [{
+                    "id": "world/world",
+                    "type": "keyword",
+                    "webTitle": "World news",
+                    "webUrl": "https://www.theguardian.com/world/world",
+                    "apiUrl": "https://content.guardianapis.com/world/world"
+                }],
+                "blocks": {
+                    "body": [{
+                        "id": "65e1a1b08f08e1a2b3c4d5e6",
+                        "bodyHtml": "<p>Update</p>",
+                        "bodyTextSummary": "Update",
+                        "attributes": {},
+                        "published": true,
+                        "createdDate": {
+                            "dateTime": 1709285100000_i64,
+                            "iso8601": "2024-03-01T09:25:00Z"
+                        },
+                        "lastModifiedDate": {
+                            "dateTime": 1709285400000_i64,
+                            "iso8601": "2024-03-01T09:30:00Z"
+                        },
+                        "contributors": [],
+                        "elements": [
+                            {
+                                "type": "text",
+                                "assets": [],
+                                "textTypeData": { "html": "<p>Update</p>" }
+                            },
+                            {
+                                "type": "callout",
+                                "assets": [],
+                                "calloutTypeData": { "campaignId": "1234" }
+                            }
+                        ]
+                    }]
+                }
+            }]
+        })
+    }
+
+    #[test]
+    fn test_search_response_round_trip() {
+        let fixture = fixture();
+        let response: SearchResponse = serde_json::from_value(fixture.clone()).unwrap();
+        let serialized = serde_json::to_value(&response).unwrap();
+
+        for member in ["status", "userTier", "total"] {
+            assert_eq!(serialized[member], fixture[member], "{member}");
+        }
+
+        let expected = &fixture["results"][0];
+        let result = &serialized["results"][0];
+        for member in [
+            "id",
+            "type",
+            "sectionId",
+            "webPublicationDate",
+            "webTitle",
+            "webUrl",
+            "apiUrl",
+            "isHosted",
+            "newField",
+        ] {
+            assert_eq!(result[member], expected[member], "{member}");
+        }
+        assert_eq!(result["fields"]["headline"], expected["fields"]["headline"]);
+        for member in ["id", "type", "webTitle", "webUrl", "apiUrl"] {
+            assert_eq!(result["tags"][0][member], expected["tags"][0][member]);
+        }
+
+        // Numbers and booleans sent as strings are serialized natively
+        assert_eq!(result["fields"]["wordcount"], 842);
+        assert_eq!(result["fields"]["liveBloggingNow"], true);
+
+        let expected = &expected["blocks"]["body"][0];
+        let block = &result["blocks"]["body"][0];
+        for member in [
+            "id",
+            "bodyHtml",
+            "bodyTextSummary",
+            "published",
+            "contributors",
+        ] {
+            assert_eq!(block[member], expected[member], "{member}");
+        }
+        for (element, expected) in [
+            (&block["elements"][0], &expected["elements"][0]),
+            (&block["elements"][1], &expected["elements"][1]),
+        ] {
+            for member in ["type", "assets"] {
+                assert_eq!(element[member], expected[member], "{member}");
+            }
+        }
+        assert_eq!(
+            block["elements"][0]["textTypeData"]["html"],
+            expected["elements"][0]["textTypeData"]["html"]
+        );
+        assert_eq!(
+            block["elements"][1]["calloutTypeData"],
+            expected["elements"][1]["calloutTypeData"]
+        );
+        assert!(block["elements"][0].get("imageTypeData").is_none());
+
+        // Block dates keep their CapiDateTime shape, and missing ones are null
+        for member in [
+            "createdDate",
+            "firstPublishedDate",
+            "publishedDate",
+            "lastModifiedDate",
+        ] {
+            assert_eq!(block[member], expected[member], "{member}");
+        }
+
+        // Serialized responses deserialize to the same values
+        let response: SearchResponse = serde_json::from_value(serialized.clone()).unwrap();
+        assert_eq!(serde_json::to_value(&response).unwrap(), serialized);
+    }
+
+    #[test]
+    fn test_content_round_trip() {
+        let content = fixture()["results"][0].clone();
+        let content: Content = serde_json::from_value(content).unwrap();
+        let serialized = serde_json::to_value(&content).unwrap();
+        let content: Content = serde_json::from_value(serialized.clone()).unwrap();
+
+        assert_eq!(serde_json::to_value(&content).unwrap(), serialized);
+    }
+
+    #[test]
+    fn test_capi_date_time() {
+        let date = json!({ "dateTime": 1709285400000_i64, "iso8601": "2024-03-01T09:30:00Z" });
+        let parsed: CapiDateTime = serde_json::from_value(date.clone()).unwrap();
+
+        assert_eq!(serde_json::to_value(&parsed).unwrap(), date);
+    }
+}
+
+#[cfg(test)]
+mod ids {
+    use crate::error::Error;
+    use crate::ids::{ContentId, EditionId, Expression, ItemId, SectionId, TagId};
+    use crate::GuardianContentClient;
+
+    #[test]
+    fn test_parse_ids() {
+        let id: ContentId = "world/2022/jan/01/funeral-of-desmond-tutu".parse().unwrap();
+        assert_eq!(id, "world/2022/jan/01/funeral-of-desmond-tutu");
+        assert_eq!(
+            "technology/apple".parse::<TagId>().unwrap(),
+            "technology/apple"
+        );
+        assert_eq!("uk-news".parse::<SectionId>().unwrap(), "uk-news");
+        assert_eq!(
+            "international".parse::<EditionId>().unwrap(),
+            "international"
+        );
+
+        assert!("world".parse::<ContentId>().is_err());
+        assert!("world//article".parse::<ContentId>().is_err());
+        assert!("world/article?page=2".parse::<ContentId>().is_err());
+        assert!("technology".parse::<TagId>().is_err());
+        assert_eq!(
+            "tracking/commissioningdesk/uk-news"
+                .parse::<TagId>()
+                .unwrap(),
+            "tracking/commissioningdesk/uk-news"
+        );
+        assert!("technology/apple/".parse::<TagId>().is_err());
+        assert!("Technology/Apple".parse::<TagId>().is_err());
+        assert!("technology/apple".parse::<SectionId>().is_err());
+        assert!(matches!("".parse::<EditionId>(), Err(Error::InvalidId(_))));
+    }
+
+    #[test]
+    fn test_ids_from_url() {
+        let id = ContentId::from_url("https://www.theguardian.com/books/2022/jan/01/2022-in-books")
+            .unwrap();
+        assert_eq!(id, "books/2022/jan/01/2022-in-books");
+
+        let tag = TagId::from_url("https://content.guardianapis.com/technology/apple").unwrap();
+        assert_eq!(tag, "technology/apple");
+
+        let section = SectionId::from_url("https://www.theguardian.com/football").unwrap();
+        assert_eq!(section, "football");
+
+        assert!(SectionId::from_url("https://www.example.com/football").is_err());
+        assert!(TagId::from_url("https://www.theguardian.com/football").is_err());
+        assert!(ContentId::from_url("not a url").is_err());
+    }
+
+    #[test]
+    fn test_content_id_from_url() {
+        let expected = "books/2022/jan/01/2022-in-books";
+
+        for url in [
+            "https://www.theguardian.com/books/2022/jan/01/2022-in-books",
+            "http://www.theguardian.com/books/2022/jan/01/2022-in-books/",
+            "https://www.theguardian.com/books/2022/jan/01/2022-in-books?CMP=share_btn_url#comments",
+            "https://amp.theguardian.com/books/2022/jan/01/2022-in-books",
+            "https://content.guardianapis.com/books/2022/jan/01/2022-in-books?show-fields=all",
+            "www.theguardian.com/books/2022/jan/01/2022-in-books",
+            "/books/2022/jan/01/2022-in-books",
+            " books/2022/jan/01/2022-in-books?page=with:block-1#block-1 ",
+        ] {
+            assert_eq!(ContentId::from_url(url).unwrap(), expected, "{url}");
+        }
+
+        for url in [
+            "https://www.example.com/books/2022/jan/01/2022-in-books",
+            "https://theguardian.com.example.com/books/2022/jan/01/2022-in-books",
+            "ftp://www.theguardian.com/books/2022/jan/01/2022-in-books",
+            "www.example.com/books/2022/jan/01/2022-in-books",
+            "https://www.theguardian.com/books",
+            "https://www.theguardian.com/",
+        ] {
+            assert!(
+                matches!(ContentId::from_url(url), Err(Error::InvalidId(_))),
+                "{url}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_builder_ids() {
+        let tag: TagId = "technology/apple".parse().unwrap();
+        let section: SectionId = "technology".parse().unwrap();
+        let request = GuardianContentClient::new("test-api-key")
+            .build_request()
+            .tag(&tag)
+            .section(section)
+            .ids(
+                ["world/article-1", "world/article-2"]
+                    .into_iter()
+                    .map(|id| id.parse::<ContentId>().unwrap())
+                    .collect::<Expression<_>>(),
+            );
+
+        assert_eq!(request.request.get("tag").unwrap(), "technology/apple");
+        assert_eq!(request.request.get("section").unwrap(), "technology");
+        assert_eq!(
+            request.request.get("ids").unwrap(),
+            "world/article-1,world/article-2"
+        );
+
+        let request = GuardianContentClient::new("test-api-key")
+            .build_request()
+            .tag("technology/apple,-type/video")
+            .section("football|-sport");
+        assert_eq!(
+            request.request.get("tag").unwrap(),
+            "technology/apple,-type/video"
+        );
+        assert_eq!(request.request.get("section").unwrap(), "football|-sport");
+        assert!(request.prepare().is_ok());
+
+        let request = GuardianContentClient::new("test-api-key")
+            .build_request()
+            .section("technology/apple");
+        assert!(!request.request.contains_key("section"));
+        assert!(matches!(request.prepare(), Err(Error::InvalidId(id)) if id == "technology/apple"));
+    }
+
+    #[test]
+    fn test_parse_expressions() {
+        for expression in [
+            "technology/apple",
+            "technology/apple,-type/video",
+            "technology/apple|technology/google",
+            "tracking/commissioningdesk/uk-news, -(type/video | type/audio)",
+            "(technology/apple|technology/google),-type/video",
+        ] {
+            let parsed: Expression<TagId> = expression.parse().unwrap();
+            assert_eq!(parsed.as_str(), expression);
+        }
+
+        for expression in [
+            "",
+            "technology",
+            "technology/apple,",
+            "technology/apple,,type/video",
+            "technology/apple|-",
+            "(technology/apple",
+            "technology/apple)",
+            "technology/apple(type/video)",
+        ] {
+            assert!(
+                matches!(
+                    expression.parse::<Expression<TagId>>(),
+                    Err(Error::InvalidId(_))
+                ),
+                "{expression}"
+            );
+        }
+
+        assert!("football|-sport".parse::<Expression<SectionId>>().is_ok());
+        assert!("football|technology/apple"
+            .parse::<Expression<SectionId>>()
+            .is_err());
+        assert!("world/article-1,world/article-2"
+            .parse::<Expression<ContentId>>()
+            .is_ok());
+    }
+
+    #[test]
+    fn test_item_ids() {
+        for id in [
+            "world/2024/jan/01/some-article",
+            "technology/apple",
+            "football",
+            "uk",
+        ] {
+            assert_eq!(id.parse::<ItemId>().unwrap().as_str(), id);
+        }
+        assert!("Football".parse::<ItemId>().is_err());
+        assert!("world//article".parse::<ItemId>().is_err());
+
+        let section: SectionId = "football".parse().unwrap();
+        assert_eq!(ItemId::from(section).as_str(), "football");
+        assert_eq!(
+            ItemId::from_url("https://www.theguardian.com/football")
+                .unwrap()
+                .as_str(),
+            "football"
+        );
+        assert!(ItemId::from_url("https://www.example.com/football").is_err());
     }
 }
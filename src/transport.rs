@@ -0,0 +1,283 @@
+//! The HTTP transport used to send requests.
+//!
+//! By default, requests are sent with [`reqwest`] through [`ReqwestTransport`].
+//! Any type implementing [`Transport`] can be used instead by setting it on
+//! the client with
+//! [`GuardianContentClientBuilder::transport`](crate::GuardianContentClientBuilder::transport).
+//!
+//! [`InMemoryTransport`] serves canned responses without any network access,
+//! which is useful to test code using the client offline.
+
+use crate::error::Error;
+use crate::ReqwestClient;
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::{StatusCode, Url};
+use std::fmt::Debug;
+use std::sync::{Arc, Mutex, PoisonError};
+
+#[cfg(not(feature = "blocking"))]
+use std::{future::Future, pin::Pin};
+
+/// The future returned by [`Transport::get`].
+#[cfg(not(feature = "blocking"))]
+pub type TransportFuture<'a> =
+    Pin<Box<dyn Future<Output = Result<HttpResponse, Error>> + Send + 'a>>;
+
+/// Sends GET requests to the API and returns the raw responses.
+///
+/// The URL passed to the transport already contains all the query
+/// parameters of the request, and the headers contain the API key.
+///
+/// Implementations should only return an error when no response could be
+/// obtained at all, e.g. because of a connection error. Error responses
+/// from the API must be returned as an [`HttpResponse`].
+#[cfg(not(feature = "blocking"))]
+pub trait Transport: Debug + Send + Sync {
+    fn get(&self, url: Url, headers: HeaderMap) -> TransportFuture<'_>;
+}
+
+/// Sends GET requests to the API and returns the raw responses.
+///
+/// The URL passed to the transport already contains all the query
+/// parameters of the request, and the headers contain the API key.
+///
+/// Implementations should only return an error when no response could be
+/// obtained at all, e.g. because of a connection error. Error responses
+/// from the API must be returned as an [`HttpResponse`].
+#[cfg(feature = "blocking")]
+pub trait Transport: Debug + Send + Sync {
+    fn get(&self, url: Url, headers: HeaderMap) -> Result<HttpResponse, Error>;
+}
+
+#[cfg(not(feature = "blocking"))]
+impl<T: Transport + ?Sized> Transport for Arc<T> {
+    fn get(&self, url: Url, headers: HeaderMap) -> TransportFuture<'_> {
+        (**self).get(url, headers)
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl<T: Transport + ?Sized> Transport for Arc<T> {
+    fn get(&self, url: Url, headers: HeaderMap) -> Result<HttpResponse, Error> {
+        (**self).get(url, headers)
+    }
+}
+
+/// A raw HTTP response, as returned by a [`Transport`].
+#[derive(Clone, Debug)]
+pub struct HttpResponse {
+    pub status: StatusCode,
+    pub headers: HeaderMap,
+    pub body: Vec<u8>,
+}
+
+impl HttpResponse {
+    /// A response with the given status and body, and no headers.
+    pub fn new(status: StatusCode, body: impl Into<Vec<u8>>) -> Self {
+        Self {
+            status,
+            headers: HeaderMap::new(),
+            body: body.into(),
+        }
+    }
+
+    /// A `200 OK` response with the given JSON body.
+    pub fn json(body: impl Into<Vec<u8>>) -> Self {
+        Self::new(StatusCode::OK, body)
+    }
+
+    /// Add a header to the response.
+    pub fn with_header(mut self, name: HeaderName, value: HeaderValue) -> Self {
+        self.headers.insert(name, value);
+        self
+    }
+}
+
+/// The default transport, sending requests with [`reqwest`].
+#[derive(Clone, Debug, Default)]
+pub struct ReqwestTransport {
+    http_client: ReqwestClient,
+}
+
+impl ReqwestTransport {
+    /// Send requests with a preconfigured `reqwest` client.
+    pub fn new(http_client: ReqwestClient) -> Self {
+        Self { http_client }
+    }
+}
+
+#[cfg(not(feature = "blocking"))]
+impl Transport for ReqwestTransport {
+    fn get(&self, url: Url, headers: HeaderMap) -> TransportFuture<'_> {
+        Box::pin(async move {
+            let response = self.http_client.get(url).headers(headers).send().await?;
+            let status = response.status();
+            let headers = response.headers().clone();
+            let body = response.bytes().await?.to_vec();
+
+            Ok(HttpResponse {
+                status,
+                headers,
+                body,
+            })
+        })
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl Transport for ReqwestTransport {
+    fn get(&self, url: Url, headers: HeaderMap) -> Result<HttpResponse, Error> {
+        let response = self.http_client.get(url).headers(headers).send()?;
+        let status = response.status();
+        let headers = response.headers().clone();
+        let body = response.bytes()?.to_vec();
+
+        Ok(HttpResponse {
+            status,
+            headers,
+            body,
+        })
+    }
+}
+
+/// A transport serving canned responses from memory.
+///
+/// Responses are matched against the path of the requested URL, relative
+/// to the base URL of the client, e.g. `search` or `tags`. A route can also
+/// require query parameters, e.g. `search?page=2`: it then only matches
+/// requests with at least those parameters. Routes are tried in the order
+/// they were added.
+///
+/// Requests that match no route get a `404 Not Found` response in the same
+/// format as the API.
+///
+/// # Example
+/// ```ignore
+/// let transport = InMemoryTransport::new()
+///         .route("search", HttpResponse::json(r#"{"response": {"status": "ok", "results": []}}"#))
+///         .route_once("tags", HttpResponse::new(StatusCode::SERVICE_UNAVAILABLE, ""));
+///
+/// let client = GuardianContentClient::builder("test")
+///         .transport(transport)
+///         .build()?;
+/// ```
+///
+/// To inspect the requests received by the transport after handing it
+/// to the client, wrap it in an [`Arc`] and pass a clone to the client.
+#[derive(Debug, Default)]
+pub struct InMemoryTransport {
+    routes: Mutex<Vec<Route>>,
+    requests: Mutex<Vec<Url>>,
+}
+
+#[derive(Debug)]
+struct Route {
+    path: String,
+    query: Vec<(String, String)>,
+    response: HttpResponse,
+    once: bool,
+}
+
+impl Route {
+    fn new(route: &str, response: HttpResponse, once: bool) -> Self {
+        let (path, query) = route.split_once('?').unwrap_or((route, ""));
+        // Safety: it's ok to unwrap here since any query string forms a valid URL
+        let query = Url::parse(&format!("http://localhost/?{query}"))
+            .unwrap()
+            .query_pairs()
+            .into_owned()
+            .collect();
+
+        Self {
+            path: path.trim_matches('/').to_owned(),
+            query,
+            response,
+            once,
+        }
+    }
+
+    /// Whether a request matches the route. The path of the request is
+    /// only compared by its end, as the base URL may contain a path too.
+    fn matches(&self, path: &str, query: &[(String, String)]) -> bool {
+        let path_matches = match path.strip_suffix(&self.path) {
+            Some(prefix) => prefix.is_empty() || prefix.ends_with('/'),
+            None => false,
+        };
+
+        path_matches && self.query.iter().all(|pair| query.contains(pair))
+    }
+}
+
+impl InMemoryTransport {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Respond with `response` to every request matching `route`.
+    pub fn route(self, route: &str, response: HttpResponse) -> Self {
+        self.add_route(Route::new(route, response, false))
+    }
+
+    /// Respond with `response` to the next request matching `route` only.
+    pub fn route_once(self, route: &str, response: HttpResponse) -> Self {
+        self.add_route(Route::new(route, response, true))
+    }
+
+    /// The URLs of all the requests received so far, in order.
+    pub fn requests(&self) -> Vec<Url> {
+        self.requests
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .clone()
+    }
+
+    fn add_route(self, route: Route) -> Self {
+        self.routes
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(route);
+        self
+    }
+
+    fn respond(&self, url: Url) -> HttpResponse {
+        let path = url
+            .path_segments()
+            .map(|segments| segments.collect::<Vec<_>>().join("/"))
+            .unwrap_or_default();
+        let query = url.query_pairs().into_owned().collect::<Vec<_>>();
+
+        self.requests
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push(url);
+
+        let mut routes = self.routes.lock().unwrap_or_else(PoisonError::into_inner);
+        let Some(index) = routes.iter().position(|route| route.matches(&path, &query)) else {
+            return HttpResponse::new(
+                StatusCode::NOT_FOUND,
+                r#"{"response":{"status":"error","message":"The requested resource could not be found."}}"#,
+            );
+        };
+
+        if routes[index].once {
+            routes.remove(index).response
+        } else {
+            routes[index].response.clone()
+        }
+    }
+}
+
+#[cfg(not(feature = "blocking"))]
+impl Transport for InMemoryTransport {
+    fn get(&self, url: Url, _headers: HeaderMap) -> TransportFuture<'_> {
+        let response = self.respond(url);
+        Box::pin(async move { Ok(response) })
+    }
+}
+
+#[cfg(feature = "blocking")]
+impl Transport for InMemoryTransport {
+    fn get(&self, url: Url, _headers: HeaderMap) -> Result<HttpResponse, Error> {
+        Ok(self.respond(url))
+    }
+}